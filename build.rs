@@ -1,8 +1,35 @@
+use std::{env, process::Command};
+
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+
+    let version = String::from_utf8(output.stdout).ok()?;
+
+    let mut pieces = version.split('.');
+
+    if pieces.next() != Some("rustc 1") {
+        return None;
+    }
+
+    pieces.next()?.parse().ok()
+}
+
 fn main() {
+    println!("cargo:rustc-check-cfg=cfg(from_windows)");
+    println!("cargo:rustc-check-cfg=cfg(from_unix)");
+    println!("cargo:rustc-check-cfg=cfg(has_core_error)");
+
     if cfg!(windows) {
         println!("cargo:rustc-cfg=from_windows");
     }
     if cfg!(unix) {
         println!("cargo:rustc-cfg=from_unix");
     }
+
+    // `core::error::Error` is stable since Rust 1.81
+    if rustc_minor_version().map_or(false, |minor| minor >= 81) {
+        println!("cargo:rustc-cfg=has_core_error");
+    }
 }
@@ -0,0 +1,370 @@
+use alloc::{borrow::Cow, string::String};
+use core::iter::FusedIterator;
+
+use crate::{Separator, SeparatorBuf};
+
+#[inline]
+fn is_any_separator(c: char) -> bool {
+    c == '/' || c == '\\'
+}
+
+/// Delete an ending slash or backslash in a string except for just a slash or a backslash.
+///
+/// ```
+/// assert_eq!(
+///     "C:/work\\proj",
+///     slash_formatter::delete_end_any_separator("C:/work\\proj/")
+/// );
+/// assert_eq!("\\", slash_formatter::delete_end_any_separator("\\"));
+/// ```
+#[inline]
+pub fn delete_end_any_separator<S: ?Sized + AsRef<str>>(s: &S) -> &str {
+    let s = s.as_ref();
+
+    let length = s.len();
+
+    if length > 1 && s.ends_with(is_any_separator) {
+        unsafe { s.get_unchecked(..length - 1) }
+    } else {
+        s
+    }
+}
+
+/// Delete an ending slash or backslash in a string except for just a slash or a backslash.
+///
+/// ```
+/// let mut s = String::from("C:/work\\proj\\");
+///
+/// slash_formatter::delete_end_any_separator_in_place(&mut s);
+///
+/// assert_eq!("C:/work\\proj", s);
+/// ```
+#[inline]
+pub fn delete_end_any_separator_in_place<B: SeparatorBuf>(s: &mut B) {
+    let length = s.as_ref().len();
+
+    if length > 1 && s.as_ref().ends_with(is_any_separator) {
+        s.truncate(length - 1);
+    }
+}
+
+/// Delete a starting slash or backslash in a string except for just a slash or a backslash.
+///
+/// ```
+/// assert_eq!("path", slash_formatter::delete_start_any_separator("\\path"));
+/// ```
+#[inline]
+pub fn delete_start_any_separator<S: ?Sized + AsRef<str>>(s: &S) -> &str {
+    let s = s.as_ref();
+
+    let length = s.len();
+
+    if length > 1 && s.starts_with(is_any_separator) {
+        unsafe { s.get_unchecked(1..) }
+    } else {
+        s
+    }
+}
+
+/// Delete a starting slash or backslash in a string except for just a slash or a backslash.
+///
+/// ```
+/// let mut s = String::from("/path");
+///
+/// slash_formatter::delete_start_any_separator_in_place(&mut s);
+///
+/// assert_eq!("path", s);
+/// ```
+#[inline]
+pub fn delete_start_any_separator_in_place<B: SeparatorBuf>(s: &mut B) {
+    let length = s.as_ref().len();
+
+    if length > 1 && s.as_ref().starts_with(is_any_separator) {
+        s.remove(0);
+    }
+}
+
+/// Add a starting `separator` into a string if it does not start with a slash or a backslash.
+///
+/// ```
+/// use slash_formatter::Slash;
+///
+/// assert_eq!(
+///     "/path",
+///     slash_formatter::add_start_any_separator("path", Slash)
+/// );
+/// assert_eq!(
+///     "\\path",
+///     slash_formatter::add_start_any_separator("\\path", Slash)
+/// );
+/// ```
+#[inline]
+pub fn add_start_any_separator<S: ?Sized + AsRef<str>, T: Separator>(
+    s: &S,
+    _separator: T,
+) -> Cow<'_, str> {
+    let s = s.as_ref();
+
+    if s.starts_with(is_any_separator) {
+        Cow::from(s)
+    } else {
+        Cow::from(format!("{}{}", T::SEPARATOR, s))
+    }
+}
+
+/// Add a starting `separator` into a string if it does not start with a slash or a backslash.
+///
+/// ```
+/// use slash_formatter::Backslash;
+///
+/// let mut s = String::from("path");
+///
+/// slash_formatter::add_start_any_separator_in_place(&mut s, Backslash);
+///
+/// assert_eq!("\\path", s);
+/// ```
+#[inline]
+pub fn add_start_any_separator_in_place<B: SeparatorBuf, T: Separator>(s: &mut B, _separator: T) {
+    if !s.as_ref().starts_with(is_any_separator) {
+        s.insert(0, T::SEPARATOR);
+    }
+}
+
+/// Add an ending `separator` into a string if it does not end with a slash or a backslash.
+///
+/// ```
+/// use slash_formatter::Slash;
+///
+/// assert_eq!("path/", slash_formatter::add_end_any_separator("path", Slash));
+/// assert_eq!(
+///     "path\\",
+///     slash_formatter::add_end_any_separator("path\\", Slash)
+/// );
+/// ```
+#[inline]
+pub fn add_end_any_separator<S: ?Sized + AsRef<str>, T: Separator>(
+    s: &S,
+    _separator: T,
+) -> Cow<'_, str> {
+    let s = s.as_ref();
+
+    if s.ends_with(is_any_separator) {
+        Cow::from(s)
+    } else {
+        Cow::from(format!("{}{}", s, T::SEPARATOR))
+    }
+}
+
+/// Add an ending `separator` into a string if it does not end with a slash or a backslash.
+///
+/// ```
+/// use slash_formatter::Slash;
+///
+/// let mut s = String::from("path");
+///
+/// slash_formatter::add_end_any_separator_in_place(&mut s, Slash);
+///
+/// assert_eq!("path/", s);
+/// ```
+#[inline]
+pub fn add_end_any_separator_in_place<B: SeparatorBuf, T: Separator>(s: &mut B, _separator: T) {
+    if !s.as_ref().ends_with(is_any_separator) {
+        s.push(T::SEPARATOR);
+    }
+}
+
+/// Concatenate two strings with `separator`. A slash or a backslash at the junction is replaced with `separator`, unless the first string is just a slash or a backslash.
+///
+/// ```
+/// use slash_formatter::{Backslash, Slash};
+///
+/// assert_eq!(
+///     "C:/work\\proj/src",
+///     slash_formatter::concat_with_any_separator(
+///         "C:/work\\proj\\",
+///         "\\src/",
+///         Slash
+///     )
+/// );
+/// assert_eq!(
+///     "C:\\work\\proj",
+///     slash_formatter::concat_with_any_separator(
+///         "C:\\work/",
+///         "/proj/",
+///         Backslash
+///     )
+/// );
+/// ```
+#[inline]
+pub fn concat_with_any_separator<S1: Into<String>, S2: AsRef<str>, T: Separator>(
+    s1: S1,
+    s2: S2,
+    separator: T,
+) -> String {
+    let mut s1 = s1.into();
+
+    concat_with_any_separator_in_place(&mut s1, s2, separator);
+
+    s1
+}
+
+/// Concatenate two strings with `separator`. A slash or a backslash at the junction is replaced with `separator`, unless the first string is just a slash or a backslash.
+///
+/// ```
+/// use slash_formatter::Slash;
+///
+/// let mut s = String::from("C:\\work\\");
+///
+/// slash_formatter::concat_with_any_separator_in_place(
+///     &mut s, "proj\\", Slash,
+/// );
+///
+/// assert_eq!("C:\\work/proj", s);
+/// ```
+#[inline]
+pub fn concat_with_any_separator_in_place<B: SeparatorBuf, S2: AsRef<str>, T: Separator>(
+    s1: &mut B,
+    s2: S2,
+    separator: T,
+) {
+    delete_end_any_separator_in_place(s1);
+    add_end_any_separator_in_place(s1, separator);
+    s1.push_str(delete_start_any_separator(s2.as_ref()));
+    delete_end_any_separator_in_place(s1);
+}
+
+/// An iterator over the segments of a string separated by slashes and backslashes, with the same rules as `Segments`.
+#[derive(Debug, Clone)]
+pub struct AnySeparatorSegments<'a> {
+    remaining: Option<&'a str>,
+}
+
+impl<'a> AnySeparatorSegments<'a> {
+    /// Get the part of the string which has not been iterated yet.
+    ///
+    /// ```
+    /// let mut segments =
+    ///     slash_formatter::any_separator_segments("/path\\to/file");
+    ///
+    /// segments.next();
+    ///
+    /// assert_eq!("to/file", segments.as_str());
+    /// ```
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        self.remaining.unwrap_or("")
+    }
+}
+
+impl<'a> Iterator for AnySeparatorSegments<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining?;
+
+        match remaining.find(is_any_separator) {
+            Some(index) => {
+                self.remaining = Some(&remaining[index + 1..]);
+
+                Some(&remaining[..index])
+            },
+            None => {
+                self.remaining = None;
+
+                Some(remaining)
+            },
+        }
+    }
+}
+
+impl<'a> DoubleEndedIterator for AnySeparatorSegments<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining?;
+
+        match remaining.rfind(is_any_separator) {
+            Some(index) => {
+                self.remaining = Some(&remaining[..index]);
+
+                Some(&remaining[index + 1..])
+            },
+            None => {
+                self.remaining = None;
+
+                Some(remaining)
+            },
+        }
+    }
+}
+
+impl<'a> FusedIterator for AnySeparatorSegments<'a> {}
+
+/// Create an iterator over the segments of a string separated by slashes and backslashes.
+///
+/// ```
+/// let segments: Vec<&str> =
+///     slash_formatter::any_separator_segments("C:/work\\proj/").collect();
+///
+/// assert_eq!(vec!["C:", "work", "proj"], segments);
+/// ```
+#[inline]
+pub fn any_separator_segments<S: ?Sized + AsRef<str>>(s: &S) -> AnySeparatorSegments<'_> {
+    let s = delete_end_any_separator(s);
+
+    let remaining = if s.is_empty() || (s.len() == 1 && s.starts_with(is_any_separator)) {
+        None
+    } else {
+        Some(delete_start_any_separator(s))
+    };
+
+    AnySeparatorSegments {
+        remaining,
+    }
+}
+
+/**
+Concatenate multiple strings with a separator, treating both slashes and backslashes in them as separators. The first argument is the `Separator` to output.
+
+```
+use slash_formatter::Slash;
+
+assert_eq!("C:\\work/proj/src", slash_formatter::any_separator!(Slash, "C:\\work\\", "\\proj/", "src\\"));
+```
+*/
+#[macro_export]
+macro_rules! any_separator {
+    ($separator:expr, $s:expr $(, $sc:expr)* $(,)*) => {
+        {
+            let mut s = $s.to_owned();
+
+            $(
+                $crate::concat_with_any_separator_in_place(&mut s, $sc, $separator);
+            )*
+
+            s
+        }
+    };
+}
+
+/**
+Concatenate multiple strings with a separator, treating both slashes and backslashes in them as separators. The first argument is the `Separator` to output.
+
+```
+use slash_formatter::Backslash;
+
+let mut s = String::from("C:/work/");
+
+slash_formatter::any_separator_in_place!(Backslash, &mut s, "/proj/", "src/");
+
+assert_eq!("C:/work\\proj\\src", s);
+```
+*/
+#[macro_export]
+macro_rules! any_separator_in_place {
+    ($separator:expr, $s:expr $(, $sc:expr)* $(,)*) => {
+        $(
+            $crate::concat_with_any_separator_in_place($s, $sc, $separator);
+        )*
+    };
+}
@@ -1,4 +1,10 @@
-use alloc::{borrow::Cow, string::String};
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::{cmp::Ordering, hash::Hasher};
+
+use crate::{
+    case_fold::{self, CaseFold},
+    Backslash, Segments, SeparatorBuf,
+};
 
 /// Delete an ending backslash in a string except for '\\\\'.
 ///
@@ -28,13 +34,11 @@ pub fn delete_end_backslash<S: ?Sized + AsRef<str>>(s: &S) -> &str {
 /// assert_eq!("path", s);
 /// ```
 #[inline]
-pub fn delete_end_backslash_in_place(s: &mut String) {
-    let length = s.len();
+pub fn delete_end_backslash_in_place<B: SeparatorBuf>(s: &mut B) {
+    let length = s.as_ref().len();
 
-    if length > 1 && s.ends_with('\\') {
-        unsafe {
-            s.as_mut_vec().set_len(length - 1);
-        }
+    if length > 1 && s.as_ref().ends_with('\\') {
+        s.truncate(length - 1);
     }
 }
 
@@ -66,10 +70,10 @@ pub fn delete_start_backslash<S: ?Sized + AsRef<str>>(s: &S) -> &str {
 /// assert_eq!("path", s);
 /// ```
 #[inline]
-pub fn delete_start_backslash_in_place(s: &mut String) {
-    let length = s.len();
+pub fn delete_start_backslash_in_place<B: SeparatorBuf>(s: &mut B) {
+    let length = s.as_ref().len();
 
-    if length > 1 && s.starts_with('\\') {
+    if length > 1 && s.as_ref().starts_with('\\') {
         s.remove(0);
     }
 }
@@ -80,6 +84,7 @@ pub fn delete_start_backslash_in_place(s: &mut String) {
 /// assert_eq!("\\path", slash_formatter::add_start_backslash("path"));
 /// ```
 #[inline]
+#[allow(unknown_lints, mismatched_lifetime_syntaxes)]
 pub fn add_start_backslash<S: ?Sized + AsRef<str>>(s: &S) -> Cow<str> {
     let s = s.as_ref();
 
@@ -100,8 +105,8 @@ pub fn add_start_backslash<S: ?Sized + AsRef<str>>(s: &S) -> Cow<str> {
 /// assert_eq!("\\path", s);
 /// ```
 #[inline]
-pub fn add_start_backslash_in_place(s: &mut String) {
-    if !s.starts_with('\\') {
+pub fn add_start_backslash_in_place<B: SeparatorBuf>(s: &mut B) {
+    if !s.as_ref().starts_with('\\') {
         s.insert(0, '\\');
     }
 }
@@ -112,6 +117,7 @@ pub fn add_start_backslash_in_place(s: &mut String) {
 /// assert_eq!("path\\", slash_formatter::add_end_backslash("path"));
 /// ```
 #[inline]
+#[allow(unknown_lints, mismatched_lifetime_syntaxes)]
 pub fn add_end_backslash<S: ?Sized + AsRef<str>>(s: &S) -> Cow<str> {
     let s = s.as_ref();
 
@@ -132,8 +138,8 @@ pub fn add_end_backslash<S: ?Sized + AsRef<str>>(s: &S) -> Cow<str> {
 /// assert_eq!("path\\", s);
 /// ```
 #[inline]
-pub fn add_end_backslash_in_place(s: &mut String) {
-    if !s.ends_with('\\') {
+pub fn add_end_backslash_in_place<B: SeparatorBuf>(s: &mut B) {
+    if !s.as_ref().ends_with('\\') {
         s.push('\\');
     }
 }
@@ -165,12 +171,160 @@ pub fn concat_with_backslash<S1: Into<String>, S2: AsRef<str>>(s1: S1, s2: S2) -
 /// assert_eq!("path\\to", s);
 /// ```
 #[inline]
-pub fn concat_with_backslash_in_place<S2: AsRef<str>>(s1: &mut String, s2: S2) {
+pub fn concat_with_backslash_in_place<B: SeparatorBuf, S2: AsRef<str>>(s1: &mut B, s2: S2) {
     add_end_backslash_in_place(s1);
     s1.push_str(delete_start_backslash(s2.as_ref()));
     delete_end_backslash_in_place(s1);
 }
 
+/// Create an iterator over the segments of a string separated by backslashes.
+///
+/// ```
+/// let segments: Vec<&str> =
+///     slash_formatter::backslash_segments("C:\\path\\to\\").collect();
+///
+/// assert_eq!(vec!["C:", "path", "to"], segments);
+/// ```
+#[inline]
+pub fn backslash_segments<S: ?Sized + AsRef<str>>(s: &S) -> Segments<'_, Backslash> {
+    Segments::new(s.as_ref())
+}
+
+/// Check whether two strings separated by backslashes are equal, ignoring ASCII case differences and an ending backslash.
+///
+/// ```
+/// assert!(slash_formatter::eq_backslash_ignore_ascii_case(
+///     "C:\\Users\\Foo",
+///     "c:\\users\\foo\\"
+/// ));
+/// ```
+#[inline]
+pub fn eq_backslash_ignore_ascii_case<S1: ?Sized + AsRef<str>, S2: ?Sized + AsRef<str>>(
+    a: &S1,
+    b: &S2,
+) -> bool {
+    case_fold::eq_ignore_case::<Backslash>(a.as_ref(), b.as_ref(), CaseFold::Ascii)
+}
+
+/// Check whether two strings separated by backslashes are equal, ignoring case differences by Unicode simple case folding and an ending backslash.
+///
+/// ```
+/// assert!(slash_formatter::eq_backslash_ignore_case(
+///     "C:\\Ÿ\\Σ",
+///     "c:\\ÿ\\ς\\"
+/// ));
+/// ```
+#[inline]
+pub fn eq_backslash_ignore_case<S1: ?Sized + AsRef<str>, S2: ?Sized + AsRef<str>>(
+    a: &S1,
+    b: &S2,
+) -> bool {
+    case_fold::eq_ignore_case::<Backslash>(a.as_ref(), b.as_ref(), CaseFold::Unicode)
+}
+
+/// Compare two strings separated by backslashes segment by segment, ignoring ASCII case differences and an ending backslash.
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(
+///     Ordering::Less,
+///     slash_formatter::cmp_backslash_ignore_ascii_case("a\\B", "A\\c")
+/// );
+/// ```
+#[inline]
+pub fn cmp_backslash_ignore_ascii_case<S1: ?Sized + AsRef<str>, S2: ?Sized + AsRef<str>>(
+    a: &S1,
+    b: &S2,
+) -> Ordering {
+    case_fold::cmp_ignore_case::<Backslash>(a.as_ref(), b.as_ref(), CaseFold::Ascii)
+}
+
+/// Compare two strings separated by backslashes segment by segment, ignoring case differences by Unicode simple case folding and an ending backslash.
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(
+///     Ordering::Equal,
+///     slash_formatter::cmp_backslash_ignore_case("Ä\\B\\", "ä\\b")
+/// );
+/// ```
+#[inline]
+pub fn cmp_backslash_ignore_case<S1: ?Sized + AsRef<str>, S2: ?Sized + AsRef<str>>(
+    a: &S1,
+    b: &S2,
+) -> Ordering {
+    case_fold::cmp_ignore_case::<Backslash>(a.as_ref(), b.as_ref(), CaseFold::Unicode)
+}
+
+/// Feed a string separated by backslashes into a hasher, consistently with `eq_backslash_ignore_ascii_case`.
+///
+/// ```
+/// use std::{collections::hash_map::DefaultHasher, hash::Hasher};
+///
+/// let mut a = DefaultHasher::new();
+/// let mut b = DefaultHasher::new();
+///
+/// slash_formatter::hash_backslash_ignore_ascii_case("C:\\Users\\Foo", &mut a);
+/// slash_formatter::hash_backslash_ignore_ascii_case(
+///     "c:\\users\\foo\\",
+///     &mut b,
+/// );
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+#[inline]
+pub fn hash_backslash_ignore_ascii_case<S: ?Sized + AsRef<str>, H: Hasher>(s: &S, state: &mut H) {
+    case_fold::hash_ignore_case::<Backslash, H>(s.as_ref(), state, CaseFold::Ascii)
+}
+
+/// Feed a string separated by backslashes into a hasher, consistently with `eq_backslash_ignore_case`.
+///
+/// ```
+/// use std::{collections::hash_map::DefaultHasher, hash::Hasher};
+///
+/// let mut a = DefaultHasher::new();
+/// let mut b = DefaultHasher::new();
+///
+/// slash_formatter::hash_backslash_ignore_case("C:\\Ÿ", &mut a);
+/// slash_formatter::hash_backslash_ignore_case("c:\\ÿ\\", &mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+#[inline]
+pub fn hash_backslash_ignore_case<S: ?Sized + AsRef<str>, H: Hasher>(s: &S, state: &mut H) {
+    case_fold::hash_ignore_case::<Backslash, H>(s.as_ref(), state, CaseFold::Unicode)
+}
+
+/// Remove strings separated by backslashes which are equal to an earlier one, ignoring ASCII case differences and an ending backslash. The first occurrences are kept in order.
+///
+/// ```
+/// let mut v = vec!["C:\\Users\\Foo", "D:\\", "c:\\users\\foo\\"];
+///
+/// slash_formatter::dedup_backslash_ignore_ascii_case(&mut v);
+///
+/// assert_eq!(vec!["C:\\Users\\Foo", "D:\\"], v);
+/// ```
+#[inline]
+pub fn dedup_backslash_ignore_ascii_case<S: AsRef<str>>(v: &mut Vec<S>) {
+    case_fold::dedup_ignore_case::<Backslash, S>(v, CaseFold::Ascii)
+}
+
+/// Remove strings separated by backslashes which are equal to an earlier one, ignoring case differences by Unicode simple case folding and an ending backslash. The first occurrences are kept in order.
+///
+/// ```
+/// let mut v = vec!["C:\\Ÿ", "D:\\", "c:\\ÿ\\"];
+///
+/// slash_formatter::dedup_backslash_ignore_case(&mut v);
+///
+/// assert_eq!(vec!["C:\\Ÿ", "D:\\"], v);
+/// ```
+#[inline]
+pub fn dedup_backslash_ignore_case<S: AsRef<str>>(v: &mut Vec<S>) {
+    case_fold::dedup_ignore_case::<Backslash, S>(v, CaseFold::Unicode)
+}
+
 /**
 Concatenate multiple strings with backslashes.
 
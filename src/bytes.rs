@@ -0,0 +1,998 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+use crate::units::{
+    add_end, add_end_in_place, add_start, add_start_in_place, concat_with_in_place, delete_end,
+    delete_end_in_place, delete_start, delete_start_in_place,
+};
+
+// Separators are ASCII, and every byte of a multi-byte UTF-8 sequence is at least 0x80, so comparing single bytes never splits a character.
+
+/// Delete an ending slash in bytes except for `b"/"`.
+///
+/// ```
+/// assert_eq!(b"path", slash_formatter::delete_end_slash_bytes(b"path/"));
+/// ```
+#[inline]
+pub fn delete_end_slash_bytes<S: ?Sized + AsRef<[u8]>>(s: &S) -> &[u8] {
+    delete_end(s.as_ref(), b'/')
+}
+
+/// Delete an ending slash in bytes except for `b"/"`.
+///
+/// ```
+/// let mut v = b"path/".to_vec();
+///
+/// slash_formatter::delete_end_slash_bytes_in_place(&mut v);
+///
+/// assert_eq!(b"path", v.as_slice());
+/// ```
+#[inline]
+pub fn delete_end_slash_bytes_in_place(s: &mut Vec<u8>) {
+    delete_end_in_place(s, b'/')
+}
+
+/// Delete a starting slash in bytes except for `b"/"`.
+///
+/// ```
+/// assert_eq!(b"path", slash_formatter::delete_start_slash_bytes(b"/path"));
+/// ```
+#[inline]
+pub fn delete_start_slash_bytes<S: ?Sized + AsRef<[u8]>>(s: &S) -> &[u8] {
+    delete_start(s.as_ref(), b'/')
+}
+
+/// Delete a starting slash in bytes except for `b"/"`.
+///
+/// ```
+/// let mut v = b"/path".to_vec();
+///
+/// slash_formatter::delete_start_slash_bytes_in_place(&mut v);
+///
+/// assert_eq!(b"path", v.as_slice());
+/// ```
+#[inline]
+pub fn delete_start_slash_bytes_in_place(s: &mut Vec<u8>) {
+    delete_start_in_place(s, b'/')
+}
+
+/// Add a starting slash into bytes.
+///
+/// ```
+/// assert_eq!(
+///     b"/path",
+///     slash_formatter::add_start_slash_bytes(b"path").as_ref()
+/// );
+/// ```
+#[inline]
+pub fn add_start_slash_bytes<S: ?Sized + AsRef<[u8]>>(s: &S) -> Cow<'_, [u8]> {
+    add_start(s.as_ref(), b'/')
+}
+
+/// Add a starting slash into bytes.
+///
+/// ```
+/// let mut v = b"path".to_vec();
+///
+/// slash_formatter::add_start_slash_bytes_in_place(&mut v);
+///
+/// assert_eq!(b"/path", v.as_slice());
+/// ```
+#[inline]
+pub fn add_start_slash_bytes_in_place(s: &mut Vec<u8>) {
+    add_start_in_place(s, b'/')
+}
+
+/// Add an ending slash into bytes.
+///
+/// ```
+/// assert_eq!(
+///     b"path/",
+///     slash_formatter::add_end_slash_bytes(b"path").as_ref()
+/// );
+/// ```
+#[inline]
+pub fn add_end_slash_bytes<S: ?Sized + AsRef<[u8]>>(s: &S) -> Cow<'_, [u8]> {
+    add_end(s.as_ref(), b'/')
+}
+
+/// Add an ending slash into bytes.
+///
+/// ```
+/// let mut v = b"path".to_vec();
+///
+/// slash_formatter::add_end_slash_bytes_in_place(&mut v);
+///
+/// assert_eq!(b"path/", v.as_slice());
+/// ```
+#[inline]
+pub fn add_end_slash_bytes_in_place(s: &mut Vec<u8>) {
+    add_end_in_place(s, b'/')
+}
+
+/// Concatenate two byte strings with a slash.
+///
+/// ```
+/// assert_eq!(
+///     b"path/to\xFF",
+///     slash_formatter::concat_with_slash_bytes(b"path".to_vec(), b"to\xFF/")
+///         .as_slice()
+/// );
+/// ```
+#[inline]
+pub fn concat_with_slash_bytes<S1: Into<Vec<u8>>, S2: AsRef<[u8]>>(s1: S1, s2: S2) -> Vec<u8> {
+    let mut s1 = s1.into();
+
+    concat_with_slash_bytes_in_place(&mut s1, s2);
+
+    s1
+}
+
+/// Concatenate two byte strings with a slash.
+///
+/// ```
+/// let mut v = b"path".to_vec();
+///
+/// slash_formatter::concat_with_slash_bytes_in_place(&mut v, b"to/");
+///
+/// assert_eq!(b"path/to", v.as_slice());
+/// ```
+#[inline]
+pub fn concat_with_slash_bytes_in_place<S2: AsRef<[u8]>>(s1: &mut Vec<u8>, s2: S2) {
+    concat_with_in_place(s1, s2.as_ref(), b'/')
+}
+
+/// Delete an ending backslash in bytes except for `b"\\"`.
+///
+/// ```
+/// assert_eq!(b"path", slash_formatter::delete_end_backslash_bytes(b"path\\"));
+/// ```
+#[inline]
+pub fn delete_end_backslash_bytes<S: ?Sized + AsRef<[u8]>>(s: &S) -> &[u8] {
+    delete_end(s.as_ref(), b'\\')
+}
+
+/// Delete an ending backslash in bytes except for `b"\\"`.
+///
+/// ```
+/// let mut v = b"path\\".to_vec();
+///
+/// slash_formatter::delete_end_backslash_bytes_in_place(&mut v);
+///
+/// assert_eq!(b"path", v.as_slice());
+/// ```
+#[inline]
+pub fn delete_end_backslash_bytes_in_place(s: &mut Vec<u8>) {
+    delete_end_in_place(s, b'\\')
+}
+
+/// Delete a starting backslash in bytes except for `b"\\"`.
+///
+/// ```
+/// assert_eq!(
+///     b"path",
+///     slash_formatter::delete_start_backslash_bytes(b"\\path")
+/// );
+/// ```
+#[inline]
+pub fn delete_start_backslash_bytes<S: ?Sized + AsRef<[u8]>>(s: &S) -> &[u8] {
+    delete_start(s.as_ref(), b'\\')
+}
+
+/// Delete a starting backslash in bytes except for `b"\\"`.
+///
+/// ```
+/// let mut v = b"\\path".to_vec();
+///
+/// slash_formatter::delete_start_backslash_bytes_in_place(&mut v);
+///
+/// assert_eq!(b"path", v.as_slice());
+/// ```
+#[inline]
+pub fn delete_start_backslash_bytes_in_place(s: &mut Vec<u8>) {
+    delete_start_in_place(s, b'\\')
+}
+
+/// Add a starting backslash into bytes.
+///
+/// ```
+/// assert_eq!(
+///     b"\\path",
+///     slash_formatter::add_start_backslash_bytes(b"path").as_ref()
+/// );
+/// ```
+#[inline]
+pub fn add_start_backslash_bytes<S: ?Sized + AsRef<[u8]>>(s: &S) -> Cow<'_, [u8]> {
+    add_start(s.as_ref(), b'\\')
+}
+
+/// Add a starting backslash into bytes.
+///
+/// ```
+/// let mut v = b"path".to_vec();
+///
+/// slash_formatter::add_start_backslash_bytes_in_place(&mut v);
+///
+/// assert_eq!(b"\\path", v.as_slice());
+/// ```
+#[inline]
+pub fn add_start_backslash_bytes_in_place(s: &mut Vec<u8>) {
+    add_start_in_place(s, b'\\')
+}
+
+/// Add an ending backslash into bytes.
+///
+/// ```
+/// assert_eq!(
+///     b"path\\",
+///     slash_formatter::add_end_backslash_bytes(b"path").as_ref()
+/// );
+/// ```
+#[inline]
+pub fn add_end_backslash_bytes<S: ?Sized + AsRef<[u8]>>(s: &S) -> Cow<'_, [u8]> {
+    add_end(s.as_ref(), b'\\')
+}
+
+/// Add an ending backslash into bytes.
+///
+/// ```
+/// let mut v = b"path".to_vec();
+///
+/// slash_formatter::add_end_backslash_bytes_in_place(&mut v);
+///
+/// assert_eq!(b"path\\", v.as_slice());
+/// ```
+#[inline]
+pub fn add_end_backslash_bytes_in_place(s: &mut Vec<u8>) {
+    add_end_in_place(s, b'\\')
+}
+
+/// Concatenate two byte strings with a backslash.
+///
+/// ```
+/// assert_eq!(
+///     b"path\\to\xFF",
+///     slash_formatter::concat_with_backslash_bytes(
+///         b"path".to_vec(),
+///         b"to\xFF\\"
+///     )
+///     .as_slice()
+/// );
+/// ```
+#[inline]
+pub fn concat_with_backslash_bytes<S1: Into<Vec<u8>>, S2: AsRef<[u8]>>(s1: S1, s2: S2) -> Vec<u8> {
+    let mut s1 = s1.into();
+
+    concat_with_backslash_bytes_in_place(&mut s1, s2);
+
+    s1
+}
+
+/// Concatenate two byte strings with a backslash.
+///
+/// ```
+/// let mut v = b"path".to_vec();
+///
+/// slash_formatter::concat_with_backslash_bytes_in_place(&mut v, b"to\\");
+///
+/// assert_eq!(b"path\\to", v.as_slice());
+/// ```
+#[inline]
+pub fn concat_with_backslash_bytes_in_place<S2: AsRef<[u8]>>(s1: &mut Vec<u8>, s2: S2) {
+    concat_with_in_place(s1, s2.as_ref(), b'\\')
+}
+
+/// Delete an ending `FILE_SEPARATOR` in bytes except for just `FILE_SEPARATOR`.
+///
+/// ```
+/// assert_eq!(
+///     b"path",
+///     slash_formatter::delete_end_file_separator_bytes(concat!(
+///         "path",
+///         slash_formatter::file_separator!()
+///     ))
+/// );
+/// ```
+#[inline]
+pub fn delete_end_file_separator_bytes<S: ?Sized + AsRef<[u8]>>(s: &S) -> &[u8] {
+    #[cfg(unix)]
+    {
+        delete_end_slash_bytes(s)
+    }
+
+    #[cfg(windows)]
+    {
+        delete_end_backslash_bytes(s)
+    }
+}
+
+/// Delete an ending `FILE_SEPARATOR` in bytes except for just `FILE_SEPARATOR`.
+///
+/// ```
+/// let mut v =
+///     concat!("path", slash_formatter::file_separator!()).as_bytes().to_vec();
+///
+/// slash_formatter::delete_end_file_separator_bytes_in_place(&mut v);
+///
+/// assert_eq!(b"path", v.as_slice());
+/// ```
+#[inline]
+pub fn delete_end_file_separator_bytes_in_place(s: &mut Vec<u8>) {
+    #[cfg(unix)]
+    {
+        delete_end_slash_bytes_in_place(s)
+    }
+
+    #[cfg(windows)]
+    {
+        delete_end_backslash_bytes_in_place(s)
+    }
+}
+
+/// Delete a starting `FILE_SEPARATOR` in bytes except for just `FILE_SEPARATOR`.
+///
+/// ```
+/// assert_eq!(
+///     b"path",
+///     slash_formatter::delete_start_file_separator_bytes(concat!(
+///         slash_formatter::file_separator!(),
+///         "path"
+///     ))
+/// );
+/// ```
+#[inline]
+pub fn delete_start_file_separator_bytes<S: ?Sized + AsRef<[u8]>>(s: &S) -> &[u8] {
+    #[cfg(unix)]
+    {
+        delete_start_slash_bytes(s)
+    }
+
+    #[cfg(windows)]
+    {
+        delete_start_backslash_bytes(s)
+    }
+}
+
+/// Delete a starting `FILE_SEPARATOR` in bytes except for just `FILE_SEPARATOR`.
+///
+/// ```
+/// let mut v =
+///     concat!(slash_formatter::file_separator!(), "path").as_bytes().to_vec();
+///
+/// slash_formatter::delete_start_file_separator_bytes_in_place(&mut v);
+///
+/// assert_eq!(b"path", v.as_slice());
+/// ```
+#[inline]
+pub fn delete_start_file_separator_bytes_in_place(s: &mut Vec<u8>) {
+    #[cfg(unix)]
+    {
+        delete_start_slash_bytes_in_place(s)
+    }
+
+    #[cfg(windows)]
+    {
+        delete_start_backslash_bytes_in_place(s)
+    }
+}
+
+/// Add a starting `FILE_SEPARATOR` into bytes.
+///
+/// ```
+/// assert_eq!(
+///     concat!(slash_formatter::file_separator!(), "path").as_bytes(),
+///     slash_formatter::add_start_file_separator_bytes("path").as_ref()
+/// );
+/// ```
+#[inline]
+pub fn add_start_file_separator_bytes<S: ?Sized + AsRef<[u8]>>(s: &S) -> Cow<'_, [u8]> {
+    #[cfg(unix)]
+    {
+        add_start_slash_bytes(s)
+    }
+
+    #[cfg(windows)]
+    {
+        add_start_backslash_bytes(s)
+    }
+}
+
+/// Add a starting `FILE_SEPARATOR` into bytes.
+///
+/// ```
+/// let mut v = b"path".to_vec();
+///
+/// slash_formatter::add_start_file_separator_bytes_in_place(&mut v);
+///
+/// assert_eq!(
+///     concat!(slash_formatter::file_separator!(), "path").as_bytes(),
+///     v.as_slice()
+/// );
+/// ```
+#[inline]
+pub fn add_start_file_separator_bytes_in_place(s: &mut Vec<u8>) {
+    #[cfg(unix)]
+    {
+        add_start_slash_bytes_in_place(s)
+    }
+
+    #[cfg(windows)]
+    {
+        add_start_backslash_bytes_in_place(s)
+    }
+}
+
+/// Add an ending `FILE_SEPARATOR` into bytes.
+///
+/// ```
+/// assert_eq!(
+///     concat!("path", slash_formatter::file_separator!()).as_bytes(),
+///     slash_formatter::add_end_file_separator_bytes("path").as_ref()
+/// );
+/// ```
+#[inline]
+pub fn add_end_file_separator_bytes<S: ?Sized + AsRef<[u8]>>(s: &S) -> Cow<'_, [u8]> {
+    #[cfg(unix)]
+    {
+        add_end_slash_bytes(s)
+    }
+
+    #[cfg(windows)]
+    {
+        add_end_backslash_bytes(s)
+    }
+}
+
+/// Add an ending `FILE_SEPARATOR` into bytes.
+///
+/// ```
+/// let mut v = b"path".to_vec();
+///
+/// slash_formatter::add_end_file_separator_bytes_in_place(&mut v);
+///
+/// assert_eq!(
+///     concat!("path", slash_formatter::file_separator!()).as_bytes(),
+///     v.as_slice()
+/// );
+/// ```
+#[inline]
+pub fn add_end_file_separator_bytes_in_place(s: &mut Vec<u8>) {
+    #[cfg(unix)]
+    {
+        add_end_slash_bytes_in_place(s)
+    }
+
+    #[cfg(windows)]
+    {
+        add_end_backslash_bytes_in_place(s)
+    }
+}
+
+/// Concatenate two byte strings with `FILE_SEPARATOR`.
+///
+/// ```
+/// assert_eq!(
+///     slash_formatter::concat_with_file_separator!("path", "to").as_bytes(),
+///     slash_formatter::concat_with_file_separator_bytes(
+///         b"path".to_vec(),
+///         b"to"
+///     )
+///     .as_slice()
+/// );
+/// ```
+#[inline]
+pub fn concat_with_file_separator_bytes<S1: Into<Vec<u8>>, S2: AsRef<[u8]>>(
+    s1: S1,
+    s2: S2,
+) -> Vec<u8> {
+    #[cfg(unix)]
+    {
+        concat_with_slash_bytes(s1, s2)
+    }
+
+    #[cfg(windows)]
+    {
+        concat_with_backslash_bytes(s1, s2)
+    }
+}
+
+/// Concatenate two byte strings with `FILE_SEPARATOR`.
+///
+/// ```
+/// let mut v = b"path".to_vec();
+///
+/// slash_formatter::concat_with_file_separator_bytes_in_place(&mut v, b"to");
+///
+/// assert_eq!(
+///     slash_formatter::concat_with_file_separator!("path", "to").as_bytes(),
+///     v.as_slice()
+/// );
+/// ```
+#[inline]
+pub fn concat_with_file_separator_bytes_in_place<S2: AsRef<[u8]>>(s1: &mut Vec<u8>, s2: S2) {
+    #[cfg(unix)]
+    {
+        concat_with_slash_bytes_in_place(s1, s2)
+    }
+
+    #[cfg(windows)]
+    {
+        concat_with_backslash_bytes_in_place(s1, s2)
+    }
+}
+
+/// Delete an ending ``FILE_SEPARATOR_ON_WORKSTATION`` in bytes except for just ``FILE_SEPARATOR_ON_WORKSTATION``.
+///
+/// ```
+/// assert_eq!(
+///     b"path",
+///     slash_formatter::delete_end_file_separator_build_bytes(concat!(
+///         "path",
+///         slash_formatter::file_separator_build!()
+///     ))
+/// );
+/// ```
+#[inline]
+pub fn delete_end_file_separator_build_bytes<S: ?Sized + AsRef<[u8]>>(s: &S) -> &[u8] {
+    #[cfg(from_unix)]
+    {
+        delete_end_slash_bytes(s)
+    }
+
+    #[cfg(from_windows)]
+    {
+        delete_end_backslash_bytes(s)
+    }
+}
+
+/// Delete an ending ``FILE_SEPARATOR_ON_WORKSTATION`` in bytes except for just ``FILE_SEPARATOR_ON_WORKSTATION``.
+///
+/// ```
+/// let mut v = concat!("path", slash_formatter::file_separator_build!())
+///     .as_bytes()
+///     .to_vec();
+///
+/// slash_formatter::delete_end_file_separator_build_bytes_in_place(&mut v);
+///
+/// assert_eq!(b"path", v.as_slice());
+/// ```
+#[inline]
+pub fn delete_end_file_separator_build_bytes_in_place(s: &mut Vec<u8>) {
+    #[cfg(from_unix)]
+    {
+        delete_end_slash_bytes_in_place(s)
+    }
+
+    #[cfg(from_windows)]
+    {
+        delete_end_backslash_bytes_in_place(s)
+    }
+}
+
+/// Delete a starting ``FILE_SEPARATOR_ON_WORKSTATION`` in bytes except for just ``FILE_SEPARATOR_ON_WORKSTATION``.
+///
+/// ```
+/// assert_eq!(
+///     b"path",
+///     slash_formatter::delete_start_file_separator_build_bytes(concat!(
+///         slash_formatter::file_separator_build!(),
+///         "path"
+///     ))
+/// );
+/// ```
+#[inline]
+pub fn delete_start_file_separator_build_bytes<S: ?Sized + AsRef<[u8]>>(s: &S) -> &[u8] {
+    #[cfg(from_unix)]
+    {
+        delete_start_slash_bytes(s)
+    }
+
+    #[cfg(from_windows)]
+    {
+        delete_start_backslash_bytes(s)
+    }
+}
+
+/// Delete a starting ``FILE_SEPARATOR_ON_WORKSTATION`` in bytes except for just ``FILE_SEPARATOR_ON_WORKSTATION``.
+///
+/// ```
+/// let mut v = concat!(slash_formatter::file_separator_build!(), "path")
+///     .as_bytes()
+///     .to_vec();
+///
+/// slash_formatter::delete_start_file_separator_build_bytes_in_place(&mut v);
+///
+/// assert_eq!(b"path", v.as_slice());
+/// ```
+#[inline]
+pub fn delete_start_file_separator_build_bytes_in_place(s: &mut Vec<u8>) {
+    #[cfg(from_unix)]
+    {
+        delete_start_slash_bytes_in_place(s)
+    }
+
+    #[cfg(from_windows)]
+    {
+        delete_start_backslash_bytes_in_place(s)
+    }
+}
+
+/// Add a starting ``FILE_SEPARATOR_ON_WORKSTATION`` into bytes.
+///
+/// ```
+/// assert_eq!(
+///     concat!(slash_formatter::file_separator_build!(), "path").as_bytes(),
+///     slash_formatter::add_start_file_separator_build_bytes("path").as_ref()
+/// );
+/// ```
+#[inline]
+pub fn add_start_file_separator_build_bytes<S: ?Sized + AsRef<[u8]>>(s: &S) -> Cow<'_, [u8]> {
+    #[cfg(from_unix)]
+    {
+        add_start_slash_bytes(s)
+    }
+
+    #[cfg(from_windows)]
+    {
+        add_start_backslash_bytes(s)
+    }
+}
+
+/// Add a starting ``FILE_SEPARATOR_ON_WORKSTATION`` into bytes.
+///
+/// ```
+/// let mut v = b"path".to_vec();
+///
+/// slash_formatter::add_start_file_separator_build_bytes_in_place(&mut v);
+///
+/// assert_eq!(
+///     concat!(slash_formatter::file_separator_build!(), "path").as_bytes(),
+///     v.as_slice()
+/// );
+/// ```
+#[inline]
+pub fn add_start_file_separator_build_bytes_in_place(s: &mut Vec<u8>) {
+    #[cfg(from_unix)]
+    {
+        add_start_slash_bytes_in_place(s)
+    }
+
+    #[cfg(from_windows)]
+    {
+        add_start_backslash_bytes_in_place(s)
+    }
+}
+
+/// Add an ending ``FILE_SEPARATOR_ON_WORKSTATION`` into bytes.
+///
+/// ```
+/// assert_eq!(
+///     concat!("path", slash_formatter::file_separator_build!()).as_bytes(),
+///     slash_formatter::add_end_file_separator_build_bytes("path").as_ref()
+/// );
+/// ```
+#[inline]
+pub fn add_end_file_separator_build_bytes<S: ?Sized + AsRef<[u8]>>(s: &S) -> Cow<'_, [u8]> {
+    #[cfg(from_unix)]
+    {
+        add_end_slash_bytes(s)
+    }
+
+    #[cfg(from_windows)]
+    {
+        add_end_backslash_bytes(s)
+    }
+}
+
+/// Add an ending ``FILE_SEPARATOR_ON_WORKSTATION`` into bytes.
+///
+/// ```
+/// let mut v = b"path".to_vec();
+///
+/// slash_formatter::add_end_file_separator_build_bytes_in_place(&mut v);
+///
+/// assert_eq!(
+///     concat!("path", slash_formatter::file_separator_build!()).as_bytes(),
+///     v.as_slice()
+/// );
+/// ```
+#[inline]
+pub fn add_end_file_separator_build_bytes_in_place(s: &mut Vec<u8>) {
+    #[cfg(from_unix)]
+    {
+        add_end_slash_bytes_in_place(s)
+    }
+
+    #[cfg(from_windows)]
+    {
+        add_end_backslash_bytes_in_place(s)
+    }
+}
+
+/// Concatenate two byte strings with ``FILE_SEPARATOR_ON_WORKSTATION``.
+///
+/// ```
+/// assert_eq!(
+///     slash_formatter::concat_with_file_separator_build!("path", "to")
+///         .as_bytes(),
+///     slash_formatter::concat_with_file_separator_build_bytes(
+///         b"path".to_vec(),
+///         b"to"
+///     )
+///     .as_slice()
+/// );
+/// ```
+#[inline]
+pub fn concat_with_file_separator_build_bytes<S1: Into<Vec<u8>>, S2: AsRef<[u8]>>(
+    s1: S1,
+    s2: S2,
+) -> Vec<u8> {
+    #[cfg(from_unix)]
+    {
+        concat_with_slash_bytes(s1, s2)
+    }
+
+    #[cfg(from_windows)]
+    {
+        concat_with_backslash_bytes(s1, s2)
+    }
+}
+
+/// Concatenate two byte strings with ``FILE_SEPARATOR_ON_WORKSTATION``.
+///
+/// ```
+/// let mut v = b"path".to_vec();
+///
+/// slash_formatter::concat_with_file_separator_build_bytes_in_place(
+///     &mut v, b"to",
+/// );
+///
+/// assert_eq!(
+///     slash_formatter::concat_with_file_separator_build!("path", "to")
+///         .as_bytes(),
+///     v.as_slice()
+/// );
+/// ```
+#[inline]
+pub fn concat_with_file_separator_build_bytes_in_place<S2: AsRef<[u8]>>(s1: &mut Vec<u8>, s2: S2) {
+    #[cfg(from_unix)]
+    {
+        concat_with_slash_bytes_in_place(s1, s2)
+    }
+
+    #[cfg(from_windows)]
+    {
+        concat_with_backslash_bytes_in_place(s1, s2)
+    }
+}
+
+/**
+Concatenate multiple byte strings with slashes. It can also be used to get the literal `b'/'`.
+
+```
+assert_eq!(b"path/to/file", slash_formatter::slash_bytes!(b"path", b"to/", b"/file/").as_slice());
+```
+*/
+#[macro_export]
+macro_rules! slash_bytes {
+    () => {
+        b'/'
+    };
+    ($s:expr $(, $sc:expr)* $(,)*) => {
+        {
+            let mut s = $s.to_vec();
+
+            $(
+                $crate::concat_with_slash_bytes_in_place(&mut s, $sc);
+            )*
+
+            s
+        }
+    };
+}
+
+/**
+Concatenate multiple byte strings with slashes. It can also be used to get the literal `b'/'`.
+
+```
+let mut v = b"path".to_vec();
+
+slash_formatter::slash_bytes_in_place!(&mut v, b"to/", b"/file/");
+
+assert_eq!(b"path/to/file", v.as_slice());
+```
+*/
+#[macro_export]
+macro_rules! slash_bytes_in_place {
+    () => {
+        b'/'
+    };
+    ($s:expr $(, $sc:expr)* $(,)*) => {
+        $(
+            $crate::concat_with_slash_bytes_in_place($s, $sc);
+        )*
+    };
+}
+
+/**
+Concatenate multiple byte strings with backslashes. It can also be used to get the literal `b'\\'`.
+
+```
+assert_eq!(b"path\\to\\file", slash_formatter::backslash_bytes!(b"path", b"to\\", b"\\file\\").as_slice());
+```
+*/
+#[macro_export]
+macro_rules! backslash_bytes {
+    () => {
+        b'\\'
+    };
+    ($s:expr $(, $sc:expr)* $(,)*) => {
+        {
+            let mut s = $s.to_vec();
+
+            $(
+                $crate::concat_with_backslash_bytes_in_place(&mut s, $sc);
+            )*
+
+            s
+        }
+    };
+}
+
+/**
+Concatenate multiple byte strings with backslashes. It can also be used to get the literal `b'\\'`.
+
+```
+let mut v = b"path".to_vec();
+
+slash_formatter::backslash_bytes_in_place!(&mut v, b"to\\", b"\\file\\");
+
+assert_eq!(b"path\\to\\file", v.as_slice());
+```
+*/
+#[macro_export]
+macro_rules! backslash_bytes_in_place {
+    () => {
+        b'\\'
+    };
+    ($s:expr $(, $sc:expr)* $(,)*) => {
+        $(
+            $crate::concat_with_backslash_bytes_in_place($s, $sc);
+        )*
+    };
+}
+
+#[cfg(unix)]
+/**
+Concatenate multiple byte strings with `FILE_SEPARATOR`. It can also be used to get the literal `FILE_SEPARATOR` as a byte.
+
+```
+assert_eq!(slash_formatter::concat_with_file_separator!("path", "to").as_bytes(), slash_formatter::file_separator_bytes!(b"path", b"to").as_slice());
+```
+*/
+#[macro_export]
+macro_rules! file_separator_bytes {
+    ($($t:tt)*) => {
+        $crate::slash_bytes!($($t)*)
+    };
+}
+
+#[cfg(windows)]
+/**
+Concatenate multiple byte strings with `FILE_SEPARATOR`. It can also be used to get the literal `FILE_SEPARATOR` as a byte.
+
+```
+assert_eq!(slash_formatter::concat_with_file_separator!("path", "to").as_bytes(), slash_formatter::file_separator_bytes!(b"path", b"to").as_slice());
+```
+*/
+#[macro_export]
+macro_rules! file_separator_bytes {
+    ($($t:tt)*) => {
+        $crate::backslash_bytes!($($t)*)
+    };
+}
+
+#[cfg(unix)]
+/**
+Concatenate multiple byte strings with `FILE_SEPARATOR`. It can also be used to get the literal `FILE_SEPARATOR` as a byte.
+
+```
+let mut v = b"path".to_vec();
+
+slash_formatter::file_separator_bytes_in_place!(&mut v, b"to");
+
+assert_eq!(slash_formatter::concat_with_file_separator!("path", "to").as_bytes(), v.as_slice());
+```
+*/
+#[macro_export]
+macro_rules! file_separator_bytes_in_place {
+    ($($t:tt)*) => {
+        $crate::slash_bytes_in_place!($($t)*)
+    };
+}
+
+#[cfg(windows)]
+/**
+Concatenate multiple byte strings with `FILE_SEPARATOR`. It can also be used to get the literal `FILE_SEPARATOR` as a byte.
+
+```
+let mut v = b"path".to_vec();
+
+slash_formatter::file_separator_bytes_in_place!(&mut v, b"to");
+
+assert_eq!(slash_formatter::concat_with_file_separator!("path", "to").as_bytes(), v.as_slice());
+```
+*/
+#[macro_export]
+macro_rules! file_separator_bytes_in_place {
+    ($($t:tt)*) => {
+        $crate::backslash_bytes_in_place!($($t)*)
+    };
+}
+
+#[cfg(from_unix)]
+/**
+Concatenate multiple byte strings with ``FILE_SEPARATOR_ON_WORKSTATION``. It can also be used to get the literal ``FILE_SEPARATOR_ON_WORKSTATION`` as a byte.
+
+```
+assert_eq!(slash_formatter::concat_with_file_separator_build!("path", "to").as_bytes(), slash_formatter::file_separator_build_bytes!(b"path", b"to").as_slice());
+```
+*/
+#[macro_export]
+macro_rules! file_separator_build_bytes {
+    ($($t:tt)*) => {
+        $crate::slash_bytes!($($t)*)
+    };
+}
+
+#[cfg(from_windows)]
+/**
+Concatenate multiple byte strings with ``FILE_SEPARATOR_ON_WORKSTATION``. It can also be used to get the literal ``FILE_SEPARATOR_ON_WORKSTATION`` as a byte.
+
+```
+assert_eq!(slash_formatter::concat_with_file_separator_build!("path", "to").as_bytes(), slash_formatter::file_separator_build_bytes!(b"path", b"to").as_slice());
+```
+*/
+#[macro_export]
+macro_rules! file_separator_build_bytes {
+    ($($t:tt)*) => {
+        $crate::backslash_bytes!($($t)*)
+    };
+}
+
+#[cfg(from_unix)]
+/**
+Concatenate multiple byte strings with ``FILE_SEPARATOR_ON_WORKSTATION``. It can also be used to get the literal ``FILE_SEPARATOR_ON_WORKSTATION`` as a byte.
+
+```
+let mut v = b"path".to_vec();
+
+slash_formatter::file_separator_build_bytes_in_place!(&mut v, b"to");
+
+assert_eq!(slash_formatter::concat_with_file_separator_build!("path", "to").as_bytes(), v.as_slice());
+```
+*/
+#[macro_export]
+macro_rules! file_separator_build_bytes_in_place {
+    ($($t:tt)*) => {
+        $crate::slash_bytes_in_place!($($t)*)
+    };
+}
+
+#[cfg(from_windows)]
+/**
+Concatenate multiple byte strings with ``FILE_SEPARATOR_ON_WORKSTATION``. It can also be used to get the literal ``FILE_SEPARATOR_ON_WORKSTATION`` as a byte.
+
+```
+let mut v = b"path".to_vec();
+
+slash_formatter::file_separator_build_bytes_in_place!(&mut v, b"to");
+
+assert_eq!(slash_formatter::concat_with_file_separator_build!("path", "to").as_bytes(), v.as_slice());
+```
+*/
+#[macro_export]
+macro_rules! file_separator_build_bytes_in_place {
+    ($($t:tt)*) => {
+        $crate::backslash_bytes_in_place!($($t)*)
+    };
+}
@@ -0,0 +1,133 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+use core::{cmp::Ordering, hash::Hasher};
+
+use crate::{Segments, Separator};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum CaseFold {
+    Ascii,
+    Unicode,
+}
+
+impl CaseFold {
+    /// Fold a character. The Unicode mode maps a character to its uppercase form and then to its lowercase form, skipping every mapping which is not one-to-one. This is the simple case folding for almost all characters.
+    #[inline]
+    fn fold(self, c: char) -> char {
+        #[inline]
+        fn single<I: Iterator<Item = char>>(mut iter: I) -> Option<char> {
+            match (iter.next(), iter.next()) {
+                (Some(c), None) => Some(c),
+                _ => None,
+            }
+        }
+
+        if c.is_ascii() {
+            return c.to_ascii_lowercase();
+        }
+
+        match self {
+            CaseFold::Ascii => c,
+            CaseFold::Unicode => {
+                let c = single(c.to_uppercase()).unwrap_or(c);
+
+                single(c.to_lowercase()).unwrap_or(c)
+            },
+        }
+    }
+
+    #[inline]
+    fn cmp_segment(self, a: &str, b: &str) -> Ordering {
+        a.chars().map(|c| self.fold(c)).cmp(b.chars().map(|c| self.fold(c)))
+    }
+}
+
+#[inline]
+fn is_rooted<S: Separator>(s: &str) -> bool {
+    s.starts_with(S::SEPARATOR)
+}
+
+pub(crate) fn cmp_ignore_case<S: Separator>(a: &str, b: &str, fold: CaseFold) -> Ordering {
+    is_rooted::<S>(a).cmp(&is_rooted::<S>(b)).then_with(|| {
+        let mut a = Segments::<S>::new(a);
+        let mut b = Segments::<S>::new(b);
+
+        loop {
+            match (a.next(), b.next()) {
+                (Some(x), Some(y)) => match fold.cmp_segment(x, y) {
+                    Ordering::Equal => (),
+                    ordering => return ordering,
+                },
+                (Some(_), None) => return Ordering::Greater,
+                (None, Some(_)) => return Ordering::Less,
+                (None, None) => return Ordering::Equal,
+            }
+        }
+    })
+}
+
+#[inline]
+pub(crate) fn eq_ignore_case<S: Separator>(a: &str, b: &str, fold: CaseFold) -> bool {
+    cmp_ignore_case::<S>(a, b, fold) == Ordering::Equal
+}
+
+pub(crate) fn hash_ignore_case<S: Separator, H: Hasher>(s: &str, state: &mut H, fold: CaseFold) {
+    state.write_u8(is_rooted::<S>(s) as u8);
+
+    for segment in Segments::<S>::new(s) {
+        for c in segment.chars() {
+            state.write_u32(fold.fold(c) as u32);
+        }
+
+        // not a valid character, so segments cannot run into each other
+        state.write_u32(u32::MAX);
+    }
+}
+
+struct Key<'a, S: Separator> {
+    s:          &'a str,
+    fold:       CaseFold,
+    _separator: core::marker::PhantomData<S>,
+}
+
+impl<'a, S: Separator> PartialEq for Key<'a, S> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        eq_ignore_case::<S>(self.s, other.s, self.fold)
+    }
+}
+
+impl<'a, S: Separator> Eq for Key<'a, S> {}
+
+impl<'a, S: Separator> PartialOrd for Key<'a, S> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a, S: Separator> Ord for Key<'a, S> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_ignore_case::<S>(self.s, other.s, self.fold)
+    }
+}
+
+pub(crate) fn dedup_ignore_case<S: Separator, T: AsRef<str>>(v: &mut Vec<T>, fold: CaseFold) {
+    let keep: Vec<bool> = {
+        let mut seen = BTreeSet::new();
+
+        v.iter()
+            .map(|s| {
+                seen.insert(Key::<S> {
+                    s: s.as_ref(),
+                    fold,
+                    _separator: core::marker::PhantomData,
+                })
+            })
+            .collect()
+    };
+
+    let mut keep = keep.into_iter();
+
+    v.retain(|_| keep.next().unwrap_or(false));
+}
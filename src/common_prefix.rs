@@ -0,0 +1,209 @@
+use alloc::vec::Vec;
+
+use crate::{windows, Backslash, FileSeparator, Separator, Slash};
+
+/// Get the length of the root of a path, which is kept even if it ends with a separator.
+#[inline]
+fn root_length<S: Separator>(s: &str) -> usize {
+    if S::SEPARATOR == '\\' && windows::has_drive_letter(s) && s[2..].starts_with('\\') {
+        3
+    } else if s.starts_with(S::SEPARATOR) {
+        S::SEPARATOR.len_utf8()
+    } else {
+        0
+    }
+}
+
+/// Get the length of the longest common prefix of two paths which ends at a segment boundary.
+fn common_length<S: Separator>(a: &str, b: &str) -> usize {
+    let separator = S::SEPARATOR as u8;
+
+    let (a_bytes, b_bytes) = (a.as_bytes(), b.as_bytes());
+
+    let i = a_bytes.iter().zip(b_bytes.iter()).take_while(|(x, y)| x == y).count();
+
+    let a_boundary = i == a_bytes.len() || a_bytes[i] == separator;
+    let b_boundary = i == b_bytes.len() || b_bytes[i] == separator;
+
+    if a_boundary && b_boundary {
+        return i;
+    }
+
+    match a_bytes[..i].iter().rposition(|&b| b == separator) {
+        Some(index) => index.max(root_length::<S>(a)),
+        None => 0,
+    }
+}
+
+fn common_prefix<'a, S: Separator>(mut paths: impl Iterator<Item = &'a str>) -> &'a str {
+    let first = match paths.next() {
+        Some(first) => first,
+        None => return "",
+    };
+
+    let mut length = first.len();
+
+    for path in paths {
+        if length == 0 {
+            break;
+        }
+
+        length = common_length::<S>(&first[..length], path);
+    }
+
+    let root_length = root_length::<S>(first);
+
+    while length > root_length && first[..length].ends_with(S::SEPARATOR) {
+        length -= S::SEPARATOR.len_utf8();
+    }
+
+    &first[..length]
+}
+
+fn strip_common_prefix<'a, S: Separator>(paths: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    let paths: Vec<&str> = paths.collect();
+
+    let prefix = common_prefix::<S>(paths.iter().copied());
+    let length = prefix.len();
+
+    // a root prefix already includes its separator, and an empty prefix must keep the root of a path
+    if length == 0 || root_length::<S>(prefix) == length {
+        return paths.into_iter().map(|path| &path[length..]).collect();
+    }
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let rest = &path[length..];
+
+            rest.strip_prefix(S::SEPARATOR).unwrap_or(rest)
+        })
+        .collect()
+}
+
+/// Get the longest common prefix of paths separated by slashes. The prefix ends at a segment boundary, and it does not end with a slash unless it is the root.
+///
+/// ```
+/// assert_eq!("/a", slash_formatter::common_prefix_slash(["/a/bc", "/a/bd"]));
+/// assert_eq!("/", slash_formatter::common_prefix_slash(["/a", "/b/"]));
+/// assert_eq!("", slash_formatter::common_prefix_slash(["a", "/a"]));
+/// assert_eq!("", slash_formatter::common_prefix_slash(Vec::<&str>::new()));
+/// ```
+#[inline]
+pub fn common_prefix_slash<'a, I: IntoIterator<Item = &'a S>, S: ?Sized + AsRef<str> + 'a>(
+    paths: I,
+) -> &'a str {
+    common_prefix::<Slash>(paths.into_iter().map(AsRef::as_ref))
+}
+
+/// Get the longest common prefix of paths separated by backslashes. The prefix ends at a segment boundary, and it does not end with a backslash unless it is the root, such as `\` or `C:\`.
+///
+/// ```
+/// assert_eq!(
+///     "C:\\Users",
+///     slash_formatter::common_prefix_backslash([
+///         "C:\\Users\\a",
+///         "C:\\Users\\ab\\"
+///     ])
+/// );
+/// assert_eq!(
+///     "C:\\",
+///     slash_formatter::common_prefix_backslash(["C:\\a", "C:\\b"])
+/// );
+/// ```
+#[inline]
+pub fn common_prefix_backslash<'a, I: IntoIterator<Item = &'a S>, S: ?Sized + AsRef<str> + 'a>(
+    paths: I,
+) -> &'a str {
+    common_prefix::<Backslash>(paths.into_iter().map(AsRef::as_ref))
+}
+
+/// Get the longest common prefix of paths separated by `FILE_SEPARATOR`. The prefix ends at a segment boundary, and it does not end with `FILE_SEPARATOR` unless it is the root.
+///
+/// ```
+/// let a = slash_formatter::concat_with_file_separator!("", "a", "bc");
+/// let b = slash_formatter::concat_with_file_separator!("", "a", "bd");
+///
+/// assert_eq!(
+///     slash_formatter::concat_with_file_separator!("", "a"),
+///     slash_formatter::common_prefix_file_separator([a, b])
+/// );
+/// ```
+#[inline]
+pub fn common_prefix_file_separator<
+    'a,
+    I: IntoIterator<Item = &'a S>,
+    S: ?Sized + AsRef<str> + 'a,
+>(
+    paths: I,
+) -> &'a str {
+    common_prefix::<FileSeparator>(paths.into_iter().map(AsRef::as_ref))
+}
+
+/// Strip the longest common prefix, as found by `common_prefix_slash`, and the slash after it from every path separated by slashes.
+///
+/// ```
+/// assert_eq!(
+///     vec!["bc", "bd/e", ""],
+///     slash_formatter::strip_common_prefix_slash(["/a/bc", "/a/bd/e", "/a/"])
+/// );
+/// assert_eq!(
+///     vec!["a", "b"],
+///     slash_formatter::strip_common_prefix_slash(["/a", "/b"])
+/// );
+/// assert_eq!(
+///     vec!["a", "/a"],
+///     slash_formatter::strip_common_prefix_slash(["a", "/a"])
+/// );
+/// ```
+#[inline]
+pub fn strip_common_prefix_slash<'a, I: IntoIterator<Item = &'a S>, S: ?Sized + AsRef<str> + 'a>(
+    paths: I,
+) -> Vec<&'a str> {
+    strip_common_prefix::<Slash>(paths.into_iter().map(AsRef::as_ref))
+}
+
+/// Strip the longest common prefix, as found by `common_prefix_backslash`, and the backslash after it from every path separated by backslashes.
+///
+/// ```
+/// assert_eq!(
+///     vec!["a", "b\\c"],
+///     slash_formatter::strip_common_prefix_backslash(["C:\\a", "C:\\b\\c"])
+/// );
+/// assert_eq!(
+///     vec!["a", "\\a"],
+///     slash_formatter::strip_common_prefix_backslash(["a", "\\a"])
+/// );
+/// ```
+#[inline]
+pub fn strip_common_prefix_backslash<
+    'a,
+    I: IntoIterator<Item = &'a S>,
+    S: ?Sized + AsRef<str> + 'a,
+>(
+    paths: I,
+) -> Vec<&'a str> {
+    strip_common_prefix::<Backslash>(paths.into_iter().map(AsRef::as_ref))
+}
+
+/// Strip the longest common prefix, as found by `common_prefix_file_separator`, and the `FILE_SEPARATOR` after it from every path separated by `FILE_SEPARATOR`.
+///
+/// ```
+/// let a = slash_formatter::concat_with_file_separator!("", "a", "bc");
+/// let b = slash_formatter::concat_with_file_separator!("", "a", "bd");
+///
+/// assert_eq!(
+///     vec!["bc", "bd"],
+///     slash_formatter::strip_common_prefix_file_separator([a, b])
+/// );
+/// ```
+#[inline]
+pub fn strip_common_prefix_file_separator<
+    'a,
+    I: IntoIterator<Item = &'a S>,
+    S: ?Sized + AsRef<str> + 'a,
+>(
+    paths: I,
+) -> Vec<&'a str> {
+    strip_common_prefix::<FileSeparator>(paths.into_iter().map(AsRef::as_ref))
+}
@@ -0,0 +1,215 @@
+use alloc::{borrow::Cow, string::String};
+use core::{
+    fmt::{self, Display, Formatter},
+    iter::FusedIterator,
+    str::CharIndices,
+};
+
+/// How to handle Unicode characters which look like slashes or backslashes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConfusableMode {
+    /// Return an error.
+    Reject,
+    /// Replace them with the ASCII separators they look like.
+    Fold,
+}
+
+/// An error indicating a Unicode character which looks like a slash or a backslash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConfusableSeparatorError {
+    /// The byte offset of the character in the string where it is found.
+    pub offset:    usize,
+    /// The look-alike character.
+    pub character: char,
+}
+
+impl Display for ConfusableSeparatorError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a character {:?} which looks like a separator at {}",
+            self.character, self.offset
+        )
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for ConfusableSeparatorError {}
+
+/// Get the ASCII separator which a Unicode character looks like, if any. `'/'` and `'\\'` themselves are not confusables.
+///
+/// ```
+/// assert_eq!(Some('/'), slash_formatter::confusable_separator('\u{2215}'));
+/// assert_eq!(Some('\\'), slash_formatter::confusable_separator('\u{29F5}'));
+/// assert_eq!(None, slash_formatter::confusable_separator('/'));
+/// ```
+#[inline]
+pub fn confusable_separator(c: char) -> Option<char> {
+    match c {
+        // FRACTION SLASH, DIVISION SLASH, BIG SOLIDUS, FULLWIDTH SOLIDUS
+        '\u{2044}' | '\u{2215}' | '\u{29F8}' | '\u{FF0F}' => Some('/'),
+        // REVERSE SOLIDUS OPERATOR, BIG REVERSE SOLIDUS, FULLWIDTH REVERSE SOLIDUS
+        '\u{29F5}' | '\u{29F9}' | '\u{FF3C}' => Some('\\'),
+        _ => None,
+    }
+}
+
+/// An iterator over the byte offsets and the characters of the look-alike separators in a string.
+#[derive(Debug, Clone)]
+pub struct ConfusableSeparators<'a> {
+    chars: CharIndices<'a>,
+}
+
+impl<'a> Iterator for ConfusableSeparators<'a> {
+    type Item = (usize, char);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chars.by_ref().find(|(_, c)| confusable_separator(*c).is_some())
+    }
+}
+
+impl<'a> DoubleEndedIterator for ConfusableSeparators<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.chars.by_ref().rfind(|(_, c)| confusable_separator(*c).is_some())
+    }
+}
+
+impl<'a> FusedIterator for ConfusableSeparators<'a> {}
+
+/// Find the Unicode characters which look like slashes or backslashes in a string, with their byte offsets.
+///
+/// ```
+/// let found: Vec<(usize, char)> =
+///     slash_formatter::confusable_separators("a\u{FF0F}b\u{29F5}c").collect();
+///
+/// assert_eq!(vec![(1, '\u{FF0F}'), (5, '\u{29F5}')], found);
+/// ```
+#[inline]
+pub fn confusable_separators<S: ?Sized + AsRef<str>>(s: &S) -> ConfusableSeparators<'_> {
+    ConfusableSeparators {
+        chars: s.as_ref().char_indices()
+    }
+}
+
+/// Return an error if a string contains a Unicode character which looks like a slash or a backslash.
+///
+/// ```
+/// use slash_formatter::ConfusableSeparatorError;
+///
+/// assert_eq!(Ok(()), slash_formatter::reject_confusable_separators("a/b"));
+/// assert_eq!(
+///     Err(ConfusableSeparatorError {
+///         offset: 1, character: '\u{2215}'
+///     }),
+///     slash_formatter::reject_confusable_separators("a\u{2215}b")
+/// );
+/// ```
+#[inline]
+pub fn reject_confusable_separators<S: ?Sized + AsRef<str>>(
+    s: &S,
+) -> Result<(), ConfusableSeparatorError> {
+    match confusable_separators(s).next() {
+        Some((offset, character)) => Err(ConfusableSeparatorError {
+            offset,
+            character,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Replace the Unicode characters which look like slashes or backslashes in a string with the ASCII separators.
+///
+/// ```
+/// assert_eq!(
+///     "a/b\\c",
+///     slash_formatter::fold_confusable_separators("a\u{2044}b\u{FF3C}c")
+/// );
+/// ```
+pub fn fold_confusable_separators<S: ?Sized + AsRef<str>>(s: &S) -> Cow<'_, str> {
+    let s = s.as_ref();
+
+    let offset = match confusable_separators(s).next() {
+        Some((offset, _)) => offset,
+        None => return Cow::from(s),
+    };
+
+    let mut folded = String::with_capacity(s.len());
+
+    folded.push_str(&s[..offset]);
+
+    for c in s[offset..].chars() {
+        folded.push(confusable_separator(c).unwrap_or(c));
+    }
+
+    Cow::from(folded)
+}
+
+#[inline]
+fn check(s: &str, mode: ConfusableMode) -> Result<Cow<'_, str>, ConfusableSeparatorError> {
+    match mode {
+        ConfusableMode::Reject => reject_confusable_separators(s).map(|_| Cow::from(s)),
+        ConfusableMode::Fold => Ok(fold_confusable_separators(s)),
+    }
+}
+
+/// Concatenate two strings with a slash, after rejecting or folding the Unicode characters which look like slashes or backslashes in them. The offset in the error is in the string where the character is found.
+///
+/// ```
+/// use slash_formatter::ConfusableMode;
+///
+/// assert_eq!(
+///     Ok(String::from("path/to/file")),
+///     slash_formatter::concat_with_slash_checked(
+///         "path\u{FF0F}",
+///         "to\u{2215}file",
+///         ConfusableMode::Fold
+///     )
+/// );
+///
+/// assert!(slash_formatter::concat_with_slash_checked(
+///     "path",
+///     "\u{2215}etc",
+///     ConfusableMode::Reject
+/// )
+/// .is_err());
+/// ```
+#[inline]
+pub fn concat_with_slash_checked<S1: AsRef<str>, S2: AsRef<str>>(
+    s1: S1,
+    s2: S2,
+    mode: ConfusableMode,
+) -> Result<String, ConfusableSeparatorError> {
+    let s1 = check(s1.as_ref(), mode)?;
+    let s2 = check(s2.as_ref(), mode)?;
+
+    Ok(crate::concat_with_slash(s1, s2))
+}
+
+/// Concatenate two strings with a backslash, after rejecting or folding the Unicode characters which look like slashes or backslashes in them. The offset in the error is in the string where the character is found.
+///
+/// ```
+/// use slash_formatter::ConfusableMode;
+///
+/// assert_eq!(
+///     Ok(String::from("path\\to\\file")),
+///     slash_formatter::concat_with_backslash_checked(
+///         "path\u{FF3C}",
+///         "to\u{29F5}file",
+///         ConfusableMode::Fold
+///     )
+/// );
+/// ```
+#[inline]
+pub fn concat_with_backslash_checked<S1: AsRef<str>, S2: AsRef<str>>(
+    s1: S1,
+    s2: S2,
+    mode: ConfusableMode,
+) -> Result<String, ConfusableSeparatorError> {
+    let s1 = check(s1.as_ref(), mode)?;
+    let s2 = check(s2.as_ref(), mode)?;
+
+    Ok(crate::concat_with_backslash(s1, s2))
+}
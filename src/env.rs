@@ -0,0 +1,360 @@
+use alloc::{borrow::Cow, string::String};
+use core::{
+    fmt::{self, Display, Formatter},
+    marker::PhantomData,
+};
+
+use crate::{Backslash, Separator, Slash};
+
+/// How to handle references to undefined environment variables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnvExpandMode {
+    /// Return an error.
+    Strict,
+    /// Keep the reference unchanged.
+    Lenient,
+}
+
+/// Errors which can occur when expanding environment variables in a path.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EnvExpandError {
+    /// The variable referenced at the byte offset is not defined. Only returned in the strict mode.
+    UndefinedVariable { offset: usize, name: String },
+    /// The `${` at the byte offset is not closed.
+    UnclosedBrace { offset: usize },
+    /// The reference at the byte offset has an empty name, such as `${}`.
+    EmptyName { offset: usize },
+}
+
+impl Display for EnvExpandError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            EnvExpandError::UndefinedVariable {
+                offset,
+                name,
+            } => write!(f, "the variable {:?} at {} is not defined", name, offset),
+            EnvExpandError::UnclosedBrace {
+                offset,
+            } => write!(f, "the `${{` at {} is not closed", offset),
+            EnvExpandError::EmptyName {
+                offset,
+            } => write!(f, "an empty variable name at {}", offset),
+        }
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for EnvExpandError {}
+
+struct Expander<'a, S: Separator, F> {
+    source:        &'a str,
+    mode:          EnvExpandMode,
+    lookup:        F,
+    expanded:      String,
+    literal_start: usize,
+    _separator:    PhantomData<S>,
+}
+
+impl<'a, S: Separator, F: FnMut(&str) -> Option<V>, V: AsRef<str>> Expander<'a, S, F> {
+    #[inline]
+    fn new(source: &'a str, mode: EnvExpandMode, lookup: F) -> Self {
+        Expander {
+            source,
+            mode,
+            lookup,
+            expanded: String::with_capacity(source.len()),
+            literal_start: 0,
+            _separator: PhantomData,
+        }
+    }
+
+    /// Push a part, collapsing the separators at the junction with the previous part.
+    #[inline]
+    fn push_part(&mut self, part: &str) {
+        if self.expanded.ends_with(S::SEPARATOR) {
+            self.expanded.push_str(part.trim_start_matches(S::SEPARATOR));
+        } else {
+            self.expanded.push_str(part);
+        }
+    }
+
+    /// Replace the reference `source[start..end]` to the variable `name`.
+    fn replace(&mut self, start: usize, end: usize, name: &str) -> Result<(), EnvExpandError> {
+        let literal = &self.source[self.literal_start..start];
+
+        self.push_part(literal);
+
+        match (self.lookup)(name) {
+            Some(value) => self.push_part(value.as_ref()),
+            None => match self.mode {
+                EnvExpandMode::Strict => {
+                    return Err(EnvExpandError::UndefinedVariable {
+                        offset: start,
+                        name:   String::from(name),
+                    })
+                },
+                EnvExpandMode::Lenient => {
+                    let reference = &self.source[start..end];
+
+                    self.push_part(reference);
+                },
+            },
+        }
+
+        self.literal_start = end;
+
+        Ok(())
+    }
+
+    /// Keep `source[start..end]` as a literal `replacement`.
+    #[inline]
+    fn escape(&mut self, start: usize, end: usize, replacement: &str) {
+        let literal = &self.source[self.literal_start..start];
+
+        self.push_part(literal);
+        self.expanded.push_str(replacement);
+
+        self.literal_start = end;
+    }
+
+    #[inline]
+    fn finish(mut self) -> String {
+        let literal = &self.source[self.literal_start..];
+
+        self.push_part(literal);
+
+        self.expanded
+    }
+}
+
+fn expand_posix<'a, S: Separator, F: FnMut(&str) -> Option<V>, V: AsRef<str>>(
+    s: &'a str,
+    mode: EnvExpandMode,
+    lookup: F,
+) -> Result<Cow<'a, str>, EnvExpandError> {
+    if !s.contains('$') {
+        return Ok(Cow::from(s));
+    }
+
+    let mut expander = Expander::<S, F>::new(s, mode, lookup);
+
+    let bytes = s.as_bytes();
+    let mut i = 0;
+
+    while let Some(index) = s[i..].find('$') {
+        let start = i + index;
+
+        if bytes.get(start + 1) == Some(&b'{') {
+            let length = match s[start + 2..].find('}') {
+                Some(length) => length,
+                None => {
+                    return Err(EnvExpandError::UnclosedBrace {
+                        offset: start
+                    })
+                },
+            };
+
+            if length == 0 {
+                return Err(EnvExpandError::EmptyName {
+                    offset: start
+                });
+            }
+
+            let end = start + 2 + length + 1;
+
+            expander.replace(start, end, &s[start + 2..end - 1])?;
+
+            i = end;
+        } else {
+            let name_length = bytes[start + 1..]
+                .iter()
+                .take_while(|b| b.is_ascii_alphanumeric() || **b == b'_')
+                .count();
+
+            if name_length == 0 || bytes[start + 1].is_ascii_digit() {
+                i = start + 1;
+
+                continue;
+            }
+
+            let end = start + 1 + name_length;
+
+            expander.replace(start, end, &s[start + 1..end])?;
+
+            i = end;
+        }
+    }
+
+    Ok(Cow::from(expander.finish()))
+}
+
+fn expand_windows<'a, S: Separator, F: FnMut(&str) -> Option<V>, V: AsRef<str>>(
+    s: &'a str,
+    mode: EnvExpandMode,
+    lookup: F,
+) -> Result<Cow<'a, str>, EnvExpandError> {
+    if !s.contains('%') {
+        return Ok(Cow::from(s));
+    }
+
+    let mut expander = Expander::<S, F>::new(s, mode, lookup);
+
+    let mut i = 0;
+
+    while let Some(index) = s[i..].find('%') {
+        let start = i + index;
+
+        let length = match s[start + 1..].find('%') {
+            Some(length) => length,
+            None => break,
+        };
+
+        let end = start + 1 + length + 1;
+
+        if length == 0 {
+            expander.escape(start, end, "%");
+        } else {
+            expander.replace(start, end, &s[start + 1..end - 1])?;
+        }
+
+        i = end;
+    }
+
+    Ok(Cow::from(expander.finish()))
+}
+
+/// Expand references to environment variables in a path separated by slashes, in the POSIX shell style, `$NAME` or `${NAME}`. A `$` which is not followed by a name is kept. Values are looked up by names, and the separators where a value meets the rest of the path are collapsed into one.
+///
+/// ```
+/// use slash_formatter::{EnvExpandError, EnvExpandMode};
+///
+/// let lookup =
+///     |name: &str| if name == "HOME" { Some("/home/u/") } else { None };
+///
+/// assert_eq!(
+///     Ok(String::from("/home/u/cache")),
+///     slash_formatter::expand_env_slash(
+///         "$HOME/cache",
+///         EnvExpandMode::Strict,
+///         lookup
+///     )
+///     .map(|s| s.into_owned())
+/// );
+///
+/// assert_eq!(
+///     Ok(String::from("/home/u/x/$UNKNOWN")),
+///     slash_formatter::expand_env_slash(
+///         "${HOME}/x/$UNKNOWN",
+///         EnvExpandMode::Lenient,
+///         lookup
+///     )
+///     .map(|s| s.into_owned())
+/// );
+///
+/// assert_eq!(
+///     Err(EnvExpandError::UndefinedVariable {
+///         offset: 6,
+///         name:   String::from("UNKNOWN"),
+///     }),
+///     slash_formatter::expand_env_slash(
+///         "/data/$UNKNOWN",
+///         EnvExpandMode::Strict,
+///         lookup
+///     )
+/// );
+/// ```
+#[inline]
+pub fn expand_env_slash<'a, S: ?Sized + AsRef<str>, F: FnMut(&str) -> Option<V>, V: AsRef<str>>(
+    s: &'a S,
+    mode: EnvExpandMode,
+    lookup: F,
+) -> Result<Cow<'a, str>, EnvExpandError> {
+    expand_posix::<Slash, F, V>(s.as_ref(), mode, lookup)
+}
+
+/// Expand references to environment variables in a path separated by backslashes, in the Windows style, `%NAME%`. `%%` is a literal `%`, and a `%` which is not closed is kept. Values are looked up by names, and the separators where a value meets the rest of the path are collapsed into one.
+///
+/// ```
+/// use slash_formatter::EnvExpandMode;
+///
+/// let lookup = |name: &str| {
+///     if name == "APPDATA" {
+///         Some("C:\\Users\\u\\AppData\\Roaming\\")
+///     } else {
+///         None
+///     }
+/// };
+///
+/// assert_eq!(
+///     Ok(String::from("C:\\Users\\u\\AppData\\Roaming\\cache")),
+///     slash_formatter::expand_env_backslash(
+///         "%APPDATA%\\cache",
+///         EnvExpandMode::Strict,
+///         lookup
+///     )
+///     .map(|s| s.into_owned())
+/// );
+///
+/// assert_eq!(
+///     Ok(String::from("100%\\%TEMP%")),
+///     slash_formatter::expand_env_backslash(
+///         "100%%\\%TEMP%",
+///         EnvExpandMode::Lenient,
+///         lookup
+///     )
+///     .map(|s| s.into_owned())
+/// );
+/// ```
+#[inline]
+pub fn expand_env_backslash<
+    'a,
+    S: ?Sized + AsRef<str>,
+    F: FnMut(&str) -> Option<V>,
+    V: AsRef<str>,
+>(
+    s: &'a S,
+    mode: EnvExpandMode,
+    lookup: F,
+) -> Result<Cow<'a, str>, EnvExpandError> {
+    expand_windows::<Backslash, F, V>(s.as_ref(), mode, lookup)
+}
+
+/// Expand references to environment variables in a path separated by `FILE_SEPARATOR`, in the style of `expand_env_slash` on Unix or `expand_env_backslash` on Windows.
+///
+/// ```
+/// use slash_formatter::EnvExpandMode;
+///
+/// if cfg!(unix) {
+///     assert_eq!(
+///         Ok(String::from("/tmp/x")),
+///         slash_formatter::expand_env_file_separator(
+///             "$TMP/x",
+///             EnvExpandMode::Strict,
+///             |_| { Some("/tmp") }
+///         )
+///         .map(|s| s.into_owned())
+///     );
+/// }
+/// ```
+#[inline]
+pub fn expand_env_file_separator<
+    'a,
+    S: ?Sized + AsRef<str>,
+    F: FnMut(&str) -> Option<V>,
+    V: AsRef<str>,
+>(
+    s: &'a S,
+    mode: EnvExpandMode,
+    lookup: F,
+) -> Result<Cow<'a, str>, EnvExpandError> {
+    #[cfg(unix)]
+    {
+        expand_env_slash(s, mode, lookup)
+    }
+
+    #[cfg(windows)]
+    {
+        expand_env_backslash(s, mode, lookup)
+    }
+}
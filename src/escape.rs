@@ -0,0 +1,327 @@
+use alloc::{borrow::Cow, string::String};
+use core::{
+    fmt::{self, Display, Formatter},
+    iter::FusedIterator,
+};
+
+use crate::SeparatorBuf;
+
+/// An error indicating a backslash at the end of an escaped string, which escapes nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DanglingEscapeError {
+    /// The byte offset of the backslash.
+    pub offset: usize,
+}
+
+impl Display for DanglingEscapeError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "a dangling backslash at {}", self.offset)
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for DanglingEscapeError {}
+
+/// An error indicating an empty first or last segment, which cannot be recovered by `escaped_segments` after joining.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EmptySegmentError {
+    /// The index of the segment.
+    pub index: usize,
+}
+
+impl Display for EmptySegmentError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "the segment at {} is empty", self.index)
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for EmptySegmentError {}
+
+/// Whether `s` ends with a `separator` which is not escaped.
+fn ends_with_unescaped(s: &str, separator: char) -> bool {
+    match s.strip_suffix(separator) {
+        Some(s) => s.bytes().rev().take_while(|b| *b == b'\\').count() % 2 == 0,
+        None => false,
+    }
+}
+
+/// Escape `separator`s and backslashes in a segment with backslashes.
+///
+/// # Panics
+///
+/// Panics if `separator` is a backslash.
+///
+/// ```
+/// assert_eq!("b\\.c", slash_formatter::escape_segment("b.c", '.'));
+/// assert_eq!("a\\/b\\\\c", slash_formatter::escape_segment("a/b\\c", '/'));
+/// ```
+pub fn escape_segment<S: ?Sized + AsRef<str>>(s: &S, separator: char) -> Cow<'_, str> {
+    assert_ne!('\\', separator, "the separator must not be a backslash");
+
+    let s = s.as_ref();
+
+    let offset = match s.find(|c| c == separator || c == '\\') {
+        Some(offset) => offset,
+        None => return Cow::from(s),
+    };
+
+    let mut escaped = String::with_capacity(s.len() + 4);
+
+    escaped.push_str(&s[..offset]);
+
+    for c in s[offset..].chars() {
+        if c == separator || c == '\\' {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+    }
+
+    Cow::from(escaped)
+}
+
+/// Remove the escaping backslashes in a segment. A backslash makes the following character literal.
+///
+/// ```
+/// use slash_formatter::DanglingEscapeError;
+///
+/// assert_eq!(Ok("b.c".into()), slash_formatter::unescape_segment("b\\.c"));
+/// assert_eq!(
+///     Err(DanglingEscapeError {
+///         offset: 1
+///     }),
+///     slash_formatter::unescape_segment("b\\")
+/// );
+/// ```
+pub fn unescape_segment<S: ?Sized + AsRef<str>>(
+    s: &S,
+) -> Result<Cow<'_, str>, DanglingEscapeError> {
+    let s = s.as_ref();
+
+    let offset = match s.find('\\') {
+        Some(offset) => offset,
+        None => return Ok(Cow::from(s)),
+    };
+
+    let mut unescaped = String::with_capacity(s.len());
+
+    unescaped.push_str(&s[..offset]);
+
+    let mut chars = s[offset..].char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some((_, c)) => unescaped.push(c),
+                None => {
+                    return Err(DanglingEscapeError {
+                        offset: offset + index
+                    })
+                },
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+
+    Ok(Cow::from(unescaped))
+}
+
+/// An iterator over the segments of a string separated by a separator which can be escaped by backslashes. The segments are yielded still escaped, so they can be passed to `unescape_segment`.
+///
+/// A starting separator and an ending separator which is not escaped are not treated as delimiters of empty segments, following the rules of `Segments`.
+#[derive(Debug, Clone)]
+pub struct EscapedSegments<'a> {
+    remaining: Option<&'a str>,
+    separator: char,
+}
+
+impl<'a> EscapedSegments<'a> {
+    /// Get the part of the string which has not been iterated yet.
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        self.remaining.unwrap_or("")
+    }
+}
+
+impl<'a> Iterator for EscapedSegments<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining?;
+
+        let mut chars = remaining.char_indices();
+
+        while let Some((index, c)) = chars.next() {
+            if c == '\\' {
+                chars.next();
+            } else if c == self.separator {
+                let rest = &remaining[index + c.len_utf8()..];
+
+                self.remaining = if rest.is_empty() { None } else { Some(rest) };
+
+                return Some(&remaining[..index]);
+            }
+        }
+
+        self.remaining = None;
+
+        Some(remaining)
+    }
+}
+
+impl<'a> FusedIterator for EscapedSegments<'a> {}
+
+/// Create an iterator over the segments of a string separated by `separator`, skipping the separators escaped by backslashes.
+///
+/// ```
+/// let segments: Vec<&str> =
+///     slash_formatter::escaped_segments("a.b\\.c", '.').collect();
+///
+/// assert_eq!(vec!["a", "b\\.c"], segments);
+///
+/// assert_eq!(
+///     Ok("b.c".into()),
+///     slash_formatter::unescape_segment(segments[1])
+/// );
+/// ```
+#[inline]
+pub fn escaped_segments<S: ?Sized + AsRef<str>>(s: &S, separator: char) -> EscapedSegments<'_> {
+    let s = s.as_ref();
+
+    let remaining = match s.strip_prefix(separator) {
+        Some("") => None,
+        Some(rest) => Some(rest),
+        None if s.is_empty() => None,
+        None => Some(s),
+    };
+
+    EscapedSegments {
+        remaining,
+        separator,
+    }
+}
+
+/// Concatenate an escaped string and a raw segment with `separator`, escaping the segment. Unlike `concat_with_*`, the ending of the segment is never trimmed, so the segment can be recovered by `escaped_segments` and `unescape_segment`.
+///
+/// # Panics
+///
+/// Panics if `separator` is a backslash.
+///
+/// ```
+/// let s = slash_formatter::concat_with_escaped("a", "b.c.", '.');
+///
+/// assert_eq!("a.b\\.c\\.", s);
+///
+/// let segments: Vec<&str> =
+///     slash_formatter::escaped_segments(&s, '.').collect();
+///
+/// assert_eq!(
+///     Ok("b.c.".into()),
+///     slash_formatter::unescape_segment(segments[1])
+/// );
+/// ```
+#[inline]
+pub fn concat_with_escaped<S1: Into<String>, S2: AsRef<str>>(
+    s1: S1,
+    s2: S2,
+    separator: char,
+) -> String {
+    let mut s1 = s1.into();
+
+    concat_with_escaped_in_place(&mut s1, s2, separator);
+
+    s1
+}
+
+/// Concatenate an escaped string and a raw segment with `separator`, escaping the segment. Unlike `concat_with_*`, the ending of the segment is never trimmed, so the segment can be recovered by `escaped_segments` and `unescape_segment`.
+///
+/// # Panics
+///
+/// Panics if `separator` is a backslash.
+///
+/// ```
+/// let mut s = String::from("a.");
+///
+/// slash_formatter::concat_with_escaped_in_place(&mut s, "b\\c", '.');
+///
+/// assert_eq!("a.b\\\\c", s);
+/// ```
+#[inline]
+pub fn concat_with_escaped_in_place<B: SeparatorBuf, S2: AsRef<str>>(
+    s1: &mut B,
+    s2: S2,
+    separator: char,
+) {
+    if !ends_with_unescaped(s1.as_ref(), separator) {
+        s1.push(separator);
+    }
+
+    s1.push_str(&escape_segment(s2.as_ref(), separator));
+}
+
+/// Join raw segments with `separator`, escaping each of them. This is the inverse of `escaped_segments` followed by `unescape_segment`. Empty segments in the middle are kept, but an empty first or last segment would be lost, so it is rejected.
+///
+/// # Panics
+///
+/// Panics if `separator` is a backslash.
+///
+/// ```
+/// use slash_formatter::EmptySegmentError;
+///
+/// let s =
+///     slash_formatter::join_escaped(["a", "", "b.c", "d\\"], '.').unwrap();
+///
+/// assert_eq!("a..b\\.c.d\\\\", s);
+///
+/// let segments: Vec<String> = slash_formatter::escaped_segments(&s, '.')
+///     .map(|segment| {
+///         slash_formatter::unescape_segment(segment).unwrap().into_owned()
+///     })
+///     .collect();
+///
+/// assert_eq!(vec!["a", "", "b.c", "d\\"], segments);
+///
+/// assert_eq!(
+///     Err(EmptySegmentError {
+///         index: 0
+///     }),
+///     slash_formatter::join_escaped(["", "a", ""], '.')
+/// );
+/// ```
+pub fn join_escaped<I: IntoIterator<Item = S>, S: AsRef<str>>(
+    segments: I,
+    separator: char,
+) -> Result<String, EmptySegmentError> {
+    let mut joined = String::new();
+    let mut last_is_empty = false;
+    let mut count = 0;
+
+    for (i, segment) in segments.into_iter().enumerate() {
+        let segment = segment.as_ref();
+
+        if i > 0 {
+            joined.push(separator);
+        } else if segment.is_empty() {
+            return Err(EmptySegmentError {
+                index: 0
+            });
+        }
+
+        joined.push_str(&escape_segment(segment, separator));
+
+        last_is_empty = segment.is_empty();
+        count = i + 1;
+    }
+
+    if last_is_empty {
+        return Err(EmptySegmentError {
+            index: count - 1
+        });
+    }
+
+    Ok(joined)
+}
@@ -1,4 +1,10 @@
+#[cfg(windows)]
+use alloc::vec::Vec;
 use alloc::{borrow::Cow, string::String};
+#[cfg(windows)]
+use core::{cmp::Ordering, hash::Hasher};
+
+use crate::{FileSeparator, Segments, SeparatorBuf};
 
 /// Delete an ending `FILE_SEPARATOR` in a string except for just `FILE_SEPARATOR`.
 ///
@@ -35,7 +41,7 @@ pub fn delete_end_file_separator<S: ?Sized + AsRef<str>>(s: &S) -> &str {
 /// assert_eq!("path", s);
 /// ```
 #[inline]
-pub fn delete_end_file_separator_in_place(s: &mut String) {
+pub fn delete_end_file_separator_in_place<B: SeparatorBuf>(s: &mut B) {
     #[cfg(unix)]
     {
         crate::delete_end_slash_in_place(s)
@@ -82,7 +88,7 @@ pub fn delete_start_file_separator<S: ?Sized + AsRef<str>>(s: &S) -> &str {
 /// assert_eq!("path", s);
 /// ```
 #[inline]
-pub fn delete_start_file_separator_in_place(s: &mut String) {
+pub fn delete_start_file_separator_in_place<B: SeparatorBuf>(s: &mut B) {
     #[cfg(unix)]
     {
         crate::delete_start_slash_in_place(s)
@@ -103,6 +109,7 @@ pub fn delete_start_file_separator_in_place(s: &mut String) {
 /// );
 /// ```
 #[inline]
+#[allow(unknown_lints, mismatched_lifetime_syntaxes)]
 pub fn add_start_file_separator<S: ?Sized + AsRef<str>>(s: &S) -> Cow<str> {
     #[cfg(unix)]
     {
@@ -125,7 +132,7 @@ pub fn add_start_file_separator<S: ?Sized + AsRef<str>>(s: &S) -> Cow<str> {
 /// assert_eq!(concat!(slash_formatter::file_separator!(), "path"), s);
 /// ```
 #[inline]
-pub fn add_start_file_separator_in_place(s: &mut String) {
+pub fn add_start_file_separator_in_place<B: SeparatorBuf>(s: &mut B) {
     #[cfg(unix)]
     {
         crate::add_start_slash_in_place(s)
@@ -146,6 +153,7 @@ pub fn add_start_file_separator_in_place(s: &mut String) {
 /// );
 /// ```
 #[inline]
+#[allow(unknown_lints, mismatched_lifetime_syntaxes)]
 pub fn add_end_file_separator<S: ?Sized + AsRef<str>>(s: &S) -> Cow<str> {
     #[cfg(unix)]
     {
@@ -168,7 +176,7 @@ pub fn add_end_file_separator<S: ?Sized + AsRef<str>>(s: &S) -> Cow<str> {
 /// assert_eq!(concat!("path", slash_formatter::file_separator!()), s);
 /// ```
 #[inline]
-pub fn add_end_file_separator_in_place(s: &mut String) {
+pub fn add_end_file_separator_in_place<B: SeparatorBuf>(s: &mut B) {
     #[cfg(unix)]
     {
         crate::add_end_slash_in_place(s)
@@ -217,7 +225,7 @@ pub fn concat_with_file_separator<S1: Into<String>, S2: AsRef<str>>(s1: S1, s2:
 /// assert_eq!(concat!("path", slash_formatter::file_separator!(), "to"), s);
 /// ```
 #[inline]
-pub fn concat_with_file_separator_in_place<S2: AsRef<str>>(s1: &mut String, s2: S2) {
+pub fn concat_with_file_separator_in_place<B: SeparatorBuf, S2: AsRef<str>>(s1: &mut B, s2: S2) {
     #[cfg(unix)]
     {
         crate::concat_with_slash_in_place(s1, s2)
@@ -229,6 +237,167 @@ pub fn concat_with_file_separator_in_place<S2: AsRef<str>>(s1: &mut String, s2:
     }
 }
 
+/// Create an iterator over the segments of a string separated by `FILE_SEPARATOR`.
+///
+/// ```
+/// let segments: Vec<&str> = slash_formatter::file_separator_segments(
+///     slash_formatter::concat_with_file_separator!("path", "to", ""),
+/// )
+/// .collect();
+///
+/// assert_eq!(vec!["path", "to"], segments);
+/// ```
+#[inline]
+pub fn file_separator_segments<S: ?Sized + AsRef<str>>(s: &S) -> Segments<'_, FileSeparator> {
+    Segments::new(s.as_ref())
+}
+
+/// Check whether two strings separated by `FILE_SEPARATOR` are equal, ignoring ASCII case differences and an ending `FILE_SEPARATOR`.
+///
+/// ```
+/// assert!(slash_formatter::eq_file_separator_ignore_ascii_case(
+///     "C:\\Users\\Foo",
+///     "c:\\users\\foo\\"
+/// ));
+/// ```
+#[cfg(windows)]
+#[inline]
+pub fn eq_file_separator_ignore_ascii_case<S1: ?Sized + AsRef<str>, S2: ?Sized + AsRef<str>>(
+    a: &S1,
+    b: &S2,
+) -> bool {
+    crate::eq_backslash_ignore_ascii_case(a, b)
+}
+
+/// Check whether two strings separated by `FILE_SEPARATOR` are equal, ignoring case differences by Unicode simple case folding and an ending `FILE_SEPARATOR`.
+///
+/// ```
+/// assert!(slash_formatter::eq_file_separator_ignore_case(
+///     "C:\\Users\\Foo",
+///     "c:\\users\\foo\\"
+/// ));
+/// ```
+#[cfg(windows)]
+#[inline]
+pub fn eq_file_separator_ignore_case<S1: ?Sized + AsRef<str>, S2: ?Sized + AsRef<str>>(
+    a: &S1,
+    b: &S2,
+) -> bool {
+    crate::eq_backslash_ignore_case(a, b)
+}
+
+/// Compare two strings separated by `FILE_SEPARATOR` segment by segment, ignoring ASCII case differences and an ending `FILE_SEPARATOR`.
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(
+///     Ordering::Less,
+///     slash_formatter::cmp_file_separator_ignore_ascii_case("a\\B", "A\\c")
+/// );
+/// ```
+#[cfg(windows)]
+#[inline]
+pub fn cmp_file_separator_ignore_ascii_case<S1: ?Sized + AsRef<str>, S2: ?Sized + AsRef<str>>(
+    a: &S1,
+    b: &S2,
+) -> Ordering {
+    crate::cmp_backslash_ignore_ascii_case(a, b)
+}
+
+/// Compare two strings separated by `FILE_SEPARATOR` segment by segment, ignoring case differences by Unicode simple case folding and an ending `FILE_SEPARATOR`.
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(
+///     Ordering::Less,
+///     slash_formatter::cmp_file_separator_ignore_case("a\\B", "A\\c")
+/// );
+/// ```
+#[cfg(windows)]
+#[inline]
+pub fn cmp_file_separator_ignore_case<S1: ?Sized + AsRef<str>, S2: ?Sized + AsRef<str>>(
+    a: &S1,
+    b: &S2,
+) -> Ordering {
+    crate::cmp_backslash_ignore_case(a, b)
+}
+
+/// Feed a string separated by `FILE_SEPARATOR` into a hasher, consistently with `eq_file_separator_ignore_ascii_case`.
+///
+/// ```
+/// use std::{collections::hash_map::DefaultHasher, hash::Hasher};
+///
+/// let mut a = DefaultHasher::new();
+/// let mut b = DefaultHasher::new();
+///
+/// slash_formatter::hash_file_separator_ignore_ascii_case("C:\\Users", &mut a);
+/// slash_formatter::hash_file_separator_ignore_ascii_case(
+///     "c:\\users\\",
+///     &mut b,
+/// );
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+#[cfg(windows)]
+#[inline]
+pub fn hash_file_separator_ignore_ascii_case<S: ?Sized + AsRef<str>, H: Hasher>(
+    s: &S,
+    state: &mut H,
+) {
+    crate::hash_backslash_ignore_ascii_case(s, state)
+}
+
+/// Feed a string separated by `FILE_SEPARATOR` into a hasher, consistently with `eq_file_separator_ignore_case`.
+///
+/// ```
+/// use std::{collections::hash_map::DefaultHasher, hash::Hasher};
+///
+/// let mut a = DefaultHasher::new();
+/// let mut b = DefaultHasher::new();
+///
+/// slash_formatter::hash_file_separator_ignore_case("C:\\Users", &mut a);
+/// slash_formatter::hash_file_separator_ignore_case("c:\\users\\", &mut b);
+///
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+#[cfg(windows)]
+#[inline]
+pub fn hash_file_separator_ignore_case<S: ?Sized + AsRef<str>, H: Hasher>(s: &S, state: &mut H) {
+    crate::hash_backslash_ignore_case(s, state)
+}
+
+/// Remove strings separated by `FILE_SEPARATOR` which are equal to an earlier one, ignoring ASCII case differences and an ending `FILE_SEPARATOR`. The first occurrences are kept in order.
+///
+/// ```
+/// let mut v = vec!["C:\\Users", "c:\\users\\"];
+///
+/// slash_formatter::dedup_file_separator_ignore_ascii_case(&mut v);
+///
+/// assert_eq!(vec!["C:\\Users"], v);
+/// ```
+#[cfg(windows)]
+#[inline]
+pub fn dedup_file_separator_ignore_ascii_case<S: AsRef<str>>(v: &mut Vec<S>) {
+    crate::dedup_backslash_ignore_ascii_case(v)
+}
+
+/// Remove strings separated by `FILE_SEPARATOR` which are equal to an earlier one, ignoring case differences by Unicode simple case folding and an ending `FILE_SEPARATOR`. The first occurrences are kept in order.
+///
+/// ```
+/// let mut v = vec!["C:\\Users", "c:\\users\\"];
+///
+/// slash_formatter::dedup_file_separator_ignore_case(&mut v);
+///
+/// assert_eq!(vec!["C:\\Users"], v);
+/// ```
+#[cfg(windows)]
+#[inline]
+pub fn dedup_file_separator_ignore_case<S: AsRef<str>>(v: &mut Vec<S>) {
+    crate::dedup_backslash_ignore_case(v)
+}
+
 #[cfg(unix)]
 /**
 Concatenate multiple strings with `FILE_SEPARATOR`. It can also be used to get the literal `FILE_SEPARATOR`.
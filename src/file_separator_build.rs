@@ -1,5 +1,7 @@
 use alloc::{borrow::Cow, string::String};
 
+use crate::{FileSeparatorBuild, Segments, SeparatorBuf};
+
 /// Delete an ending ``FILE_SEPARATOR_ON_WORKSTATION`` in a string except for just ``FILE_SEPARATOR_ON_WORKSTATION``.
 ///
 /// ```
@@ -35,7 +37,7 @@ pub fn delete_end_file_separator_build<S: ?Sized + AsRef<str>>(s: &S) -> &str {
 /// assert_eq!("path", s);
 /// ```
 #[inline]
-pub fn delete_end_file_separator_build_in_place(s: &mut String) {
+pub fn delete_end_file_separator_build_in_place<B: SeparatorBuf>(s: &mut B) {
     #[cfg(from_unix)]
     {
         crate::delete_end_slash_in_place(s)
@@ -82,7 +84,7 @@ pub fn delete_start_file_separator_build<S: ?Sized + AsRef<str>>(s: &S) -> &str
 /// assert_eq!("path", s);
 /// ```
 #[inline]
-pub fn delete_start_file_separator_build_in_place(s: &mut String) {
+pub fn delete_start_file_separator_build_in_place<B: SeparatorBuf>(s: &mut B) {
     #[cfg(from_unix)]
     {
         crate::delete_start_slash_in_place(s)
@@ -103,6 +105,7 @@ pub fn delete_start_file_separator_build_in_place(s: &mut String) {
 /// );
 /// ```
 #[inline]
+#[allow(unknown_lints, mismatched_lifetime_syntaxes)]
 pub fn add_start_file_separator_build<S: ?Sized + AsRef<str>>(s: &S) -> Cow<str> {
     #[cfg(from_unix)]
     {
@@ -125,7 +128,7 @@ pub fn add_start_file_separator_build<S: ?Sized + AsRef<str>>(s: &S) -> Cow<str>
 /// assert_eq!(concat!(slash_formatter::file_separator_build!(), "path"), s);
 /// ```
 #[inline]
-pub fn add_start_file_separator_build_in_place(s: &mut String) {
+pub fn add_start_file_separator_build_in_place<B: SeparatorBuf>(s: &mut B) {
     #[cfg(from_unix)]
     {
         crate::add_start_slash_in_place(s)
@@ -146,6 +149,7 @@ pub fn add_start_file_separator_build_in_place(s: &mut String) {
 /// );
 /// ```
 #[inline]
+#[allow(unknown_lints, mismatched_lifetime_syntaxes)]
 pub fn add_end_file_separator_build<S: ?Sized + AsRef<str>>(s: &S) -> Cow<str> {
     #[cfg(from_unix)]
     {
@@ -168,7 +172,7 @@ pub fn add_end_file_separator_build<S: ?Sized + AsRef<str>>(s: &S) -> Cow<str> {
 /// assert_eq!(concat!("path", slash_formatter::file_separator_build!()), s);
 /// ```
 #[inline]
-pub fn add_end_file_separator_build_in_place(s: &mut String) {
+pub fn add_end_file_separator_build_in_place<B: SeparatorBuf>(s: &mut B) {
     #[cfg(from_unix)]
     {
         crate::add_end_slash_in_place(s)
@@ -223,7 +227,10 @@ pub fn concat_with_file_separator_build<S1: Into<String>, S2: AsRef<str>>(
 /// );
 /// ```
 #[inline]
-pub fn concat_with_file_separator_build_in_place<S2: AsRef<str>>(s1: &mut String, s2: S2) {
+pub fn concat_with_file_separator_build_in_place<B: SeparatorBuf, S2: AsRef<str>>(
+    s1: &mut B,
+    s2: S2,
+) {
     #[cfg(from_unix)]
     {
         crate::concat_with_slash_in_place(s1, s2)
@@ -235,6 +242,23 @@ pub fn concat_with_file_separator_build_in_place<S2: AsRef<str>>(s1: &mut String
     }
 }
 
+/// Create an iterator over the segments of a string separated by ``FILE_SEPARATOR_ON_WORKSTATION``.
+///
+/// ```
+/// let segments: Vec<&str> = slash_formatter::file_separator_build_segments(
+///     slash_formatter::concat_with_file_separator_build!("path", "to", ""),
+/// )
+/// .collect();
+///
+/// assert_eq!(vec!["path", "to"], segments);
+/// ```
+#[inline]
+pub fn file_separator_build_segments<S: ?Sized + AsRef<str>>(
+    s: &S,
+) -> Segments<'_, FileSeparatorBuild> {
+    Segments::new(s.as_ref())
+}
+
 #[cfg(from_unix)]
 /**
 Concatenate multiple strings with ``FILE_SEPARATOR_ON_WORKSTATION``. It can also be used to get the literal ``FILE_SEPARATOR_ON_WORKSTATION``.
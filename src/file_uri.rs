@@ -0,0 +1,399 @@
+use alloc::{borrow::Cow, string::String};
+use core::fmt::{self, Display, Formatter};
+
+use crate::{
+    percent::{self, DecodeError},
+    windows,
+};
+
+/// Errors which can occur when converting between paths and `file://` URIs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileUriError {
+    /// The path is not absolute, so it cannot be converted into a URI.
+    NotAbsolute,
+    /// The URI does not use the `file` scheme.
+    NotFileUri,
+    /// The URI has a host which cannot be represented in this kind of path.
+    UnsupportedHost,
+    /// A `%` which is not followed by two hexadecimal digits at the byte offset of the path part of the URI.
+    InvalidPercentEncoding { offset: usize },
+    /// The percent-decoded path is not valid UTF-8.
+    InvalidUtf8,
+    /// A percent-encoded separator, such as `%2F`, at the byte offset of the host or the path part of the URI, which cannot be represented in a path.
+    EncodedSeparator { offset: usize },
+    /// A NUL byte, which may be percent-encoded, at the byte offset of the host or the path part of the URI.
+    NulByte { offset: usize },
+}
+
+impl Display for FileUriError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FileUriError::NotAbsolute => f.write_str("the path is not absolute"),
+            FileUriError::NotFileUri => f.write_str("the URI does not use the file scheme"),
+            FileUriError::UnsupportedHost => f.write_str("the host of the URI is not supported"),
+            FileUriError::InvalidPercentEncoding {
+                offset,
+            } => write!(f, "an invalid percent-encoding at {}", offset),
+            FileUriError::InvalidUtf8 => f.write_str("the decoded path is not valid UTF-8"),
+            FileUriError::EncodedSeparator {
+                offset,
+            } => write!(f, "an encoded separator at {}", offset),
+            FileUriError::NulByte {
+                offset,
+            } => write!(f, "a NUL byte at {}", offset),
+        }
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for FileUriError {}
+
+impl From<DecodeError> for FileUriError {
+    #[inline]
+    fn from(err: DecodeError) -> Self {
+        match err {
+            DecodeError::Malformed(offset) => FileUriError::InvalidPercentEncoding {
+                offset,
+            },
+            DecodeError::InvalidUtf8 => FileUriError::InvalidUtf8,
+        }
+    }
+}
+
+/// Split a `file` URI into its host (empty for the local machine) and its percent-encoded path. The query and the fragment are dropped.
+fn split_file_uri(uri: &str) -> Result<(&str, &str), FileUriError> {
+    match uri.get(..5) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("file:") => (),
+        _ => return Err(FileUriError::NotFileUri),
+    }
+
+    let rest = &uri[5..];
+    let rest = &rest[..rest.find(|c: char| c == '?' || c == '#').unwrap_or(rest.len())];
+
+    let (host, path) = match rest.strip_prefix("//") {
+        Some(authority_and_path) => {
+            let index = authority_and_path.find('/').unwrap_or(authority_and_path.len());
+
+            authority_and_path.split_at(index)
+        },
+        None => ("", rest),
+    };
+
+    let host = if host.eq_ignore_ascii_case("localhost") { "" } else { host };
+
+    if !path.starts_with('/') && host.is_empty() {
+        return Err(FileUriError::NotAbsolute);
+    }
+
+    Ok((host, path))
+}
+
+/// Push the segments of a path as an absolute URI path. Every segment, including an empty one, is percent-encoded and preceded by a slash.
+fn push_encoded_path<'a>(uri: &mut String, segments: impl Iterator<Item = &'a str>) {
+    for segment in segments {
+        uri.push('/');
+
+        percent::push_encoded_segment(uri, segment);
+    }
+}
+
+/// Decode the host or the path part of a `file` URI, rejecting NUL bytes and percent-encoded `separators`, which would become real separators after decoding.
+fn decode_path<'a>(path: &'a str, separators: &[u8]) -> Result<Cow<'a, str>, FileUriError> {
+    if let Some(offset) = path.find('\0').or_else(|| percent::find_encoded(path, |b| b == 0)) {
+        return Err(FileUriError::NulByte {
+            offset,
+        });
+    }
+
+    if let Some(offset) = percent::find_encoded(path, |b| separators.contains(&b)) {
+        return Err(FileUriError::EncodedSeparator {
+            offset,
+        });
+    }
+
+    Ok(percent::decode(path)?)
+}
+
+/// Convert an absolute path separated by slashes into a `file://` URI. Characters which are not allowed in a URI path are percent-encoded, and empty segments are kept.
+///
+/// ```
+/// use slash_formatter::FileUriError;
+///
+/// assert_eq!(
+///     Ok(String::from("file:///home/u/a%20b")),
+///     slash_formatter::path_to_file_uri_slash("/home/u/a b")
+/// );
+///
+/// assert_eq!(
+///     Ok(String::from("file:///a//b/")),
+///     slash_formatter::path_to_file_uri_slash("/a//b/")
+/// );
+///
+/// assert_eq!(
+///     Err(FileUriError::NotAbsolute),
+///     slash_formatter::path_to_file_uri_slash("home/u")
+/// );
+/// ```
+#[inline]
+pub fn path_to_file_uri_slash<S: ?Sized + AsRef<str>>(s: &S) -> Result<String, FileUriError> {
+    let s = s.as_ref();
+
+    if !s.starts_with('/') {
+        return Err(FileUriError::NotAbsolute);
+    }
+
+    let mut uri = String::with_capacity(s.len() + 7);
+
+    uri.push_str("file://");
+
+    push_encoded_path(&mut uri, s[1..].split('/'));
+
+    Ok(uri)
+}
+
+/// Convert an absolute path separated by backslashes, which starts with a drive letter and a backslash or is a UNC path, into a `file://` URI. Characters which are not allowed in a URI path are percent-encoded, and empty segments are kept. A drive-relative path, such as `C:` or `C:x`, and a UNC path without a host are not absolute.
+///
+/// ```
+/// use slash_formatter::FileUriError;
+///
+/// assert_eq!(
+///     Ok(String::from("file:///C:/Program%20Files/x")),
+///     slash_formatter::path_to_file_uri_backslash("C:\\Program Files\\x")
+/// );
+///
+/// assert_eq!(
+///     Ok(String::from("file://server/share/x")),
+///     slash_formatter::path_to_file_uri_backslash("\\\\server\\share\\x")
+/// );
+///
+/// assert_eq!(
+///     Ok(String::from("file:///C:/")),
+///     slash_formatter::path_to_file_uri_backslash("C:\\")
+/// );
+///
+/// assert_eq!(
+///     Err(FileUriError::NotAbsolute),
+///     slash_formatter::path_to_file_uri_backslash("C:")
+/// );
+///
+/// assert_eq!(
+///     Err(FileUriError::NotAbsolute),
+///     slash_formatter::path_to_file_uri_backslash("\\\\\\x")
+/// );
+/// ```
+pub fn path_to_file_uri_backslash<S: ?Sized + AsRef<str>>(s: &S) -> Result<String, FileUriError> {
+    let s = s.as_ref();
+
+    let (unc, s) = match s.strip_prefix("\\\\?\\") {
+        Some(verbatim) => (verbatim.strip_prefix("UNC\\"), verbatim),
+        None => (s.strip_prefix("\\\\"), s),
+    };
+
+    let (host, rest) = if let Some(authority_and_path) = unc {
+        let (host, rest) = match authority_and_path.find('\\') {
+            Some(index) => (&authority_and_path[..index], &authority_and_path[index + 1..]),
+            None => (authority_and_path, ""),
+        };
+
+        // `\\\x` has no host, and it would otherwise become `file:///x`
+        if host.is_empty() {
+            return Err(FileUriError::NotAbsolute);
+        }
+
+        (host, rest)
+    } else if windows::has_drive_letter(s) && s[2..].starts_with('\\') {
+        ("", s)
+    } else {
+        return Err(FileUriError::NotAbsolute);
+    };
+
+    let mut uri = String::with_capacity(s.len() + 8);
+
+    uri.push_str("file://");
+
+    percent::push_encoded_segment(&mut uri, host);
+
+    push_encoded_path(&mut uri, rest.split('\\'));
+
+    Ok(uri)
+}
+
+/// Convert an absolute path separated by `FILE_SEPARATOR` into a `file://` URI.
+///
+/// ```
+/// let path = slash_formatter::concat_with_file_separator!("", "a b");
+///
+/// if cfg!(unix) {
+///     assert_eq!(
+///         Ok(String::from("file:///a%20b")),
+///         slash_formatter::path_to_file_uri_file_separator(path)
+///     );
+/// }
+/// ```
+#[inline]
+pub fn path_to_file_uri_file_separator<S: ?Sized + AsRef<str>>(
+    s: &S,
+) -> Result<String, FileUriError> {
+    #[cfg(unix)]
+    {
+        path_to_file_uri_slash(s)
+    }
+
+    #[cfg(windows)]
+    {
+        path_to_file_uri_backslash(s)
+    }
+}
+
+/// Convert a `file://` URI into an absolute path separated by slashes. URIs with a host other than `localhost` are not supported, and NUL bytes and percent-encoded slashes are rejected.
+///
+/// ```
+/// use slash_formatter::FileUriError;
+///
+/// assert_eq!(
+///     Ok(String::from("/home/u/a b")),
+///     slash_formatter::file_uri_to_slash("file:///home/u/a%20b")
+/// );
+///
+/// assert_eq!(
+///     Ok(String::from("/etc")),
+///     slash_formatter::file_uri_to_slash("file://localhost/etc")
+/// );
+///
+/// assert_eq!(
+///     Err(FileUriError::UnsupportedHost),
+///     slash_formatter::file_uri_to_slash("file://server/share")
+/// );
+///
+/// assert_eq!(
+///     Err(FileUriError::EncodedSeparator {
+///         offset: 2
+///     }),
+///     slash_formatter::file_uri_to_slash("file:///a%2Fb")
+/// );
+///
+/// assert_eq!(
+///     Err(FileUriError::NulByte {
+///         offset: 2
+///     }),
+///     slash_formatter::file_uri_to_slash("file:///a%00")
+/// );
+/// ```
+#[inline]
+pub fn file_uri_to_slash<S: ?Sized + AsRef<str>>(uri: &S) -> Result<String, FileUriError> {
+    let (host, path) = split_file_uri(uri.as_ref())?;
+
+    if !host.is_empty() {
+        return Err(FileUriError::UnsupportedHost);
+    }
+
+    Ok(decode_path(path, b"/")?.into_owned())
+}
+
+/// Convert a `file://` URI into an absolute path separated by backslashes. A host becomes a UNC prefix. NUL bytes and percent-encoded slashes and backslashes in the host or the path are rejected.
+///
+/// ```
+/// use slash_formatter::FileUriError;
+///
+/// assert_eq!(
+///     Ok(String::from("C:\\Program Files\\x")),
+///     slash_formatter::file_uri_to_backslash("file:///C:/Program%20Files/x")
+/// );
+///
+/// assert_eq!(
+///     Ok(String::from("\\\\server\\share\\x")),
+///     slash_formatter::file_uri_to_backslash("file://server/share/x")
+/// );
+///
+/// assert_eq!(
+///     Ok(String::from("C:\\")),
+///     slash_formatter::file_uri_to_backslash("file:///C:")
+/// );
+///
+/// assert_eq!(
+///     Err(FileUriError::EncodedSeparator {
+///         offset: 1
+///     }),
+///     slash_formatter::file_uri_to_backslash("file://a%2Fb/x")
+/// );
+///
+/// assert_eq!(
+///     Err(FileUriError::EncodedSeparator {
+///         offset: 4
+///     }),
+///     slash_formatter::file_uri_to_backslash("file://evil%5C..%5C/x")
+/// );
+///
+/// assert_eq!(
+///     Err(FileUriError::NulByte {
+///         offset: 1
+///     }),
+///     slash_formatter::file_uri_to_backslash("file://a%00/x")
+/// );
+/// ```
+pub fn file_uri_to_backslash<S: ?Sized + AsRef<str>>(uri: &S) -> Result<String, FileUriError> {
+    let (host, path) = split_file_uri(uri.as_ref())?;
+
+    if host.contains('\\') {
+        return Err(FileUriError::UnsupportedHost);
+    }
+
+    let host = decode_path(host, b"/\\")?;
+    let path = decode_path(path, b"/\\")?;
+
+    let mut s = String::with_capacity(host.len() + path.len() + 2);
+
+    if host.is_empty() {
+        let without_root = crate::delete_start_slash(path.as_ref());
+
+        let bytes = without_root.as_bytes();
+
+        // `C|` is a legacy form of `C:`
+        if bytes.len() >= 2
+            && bytes[0].is_ascii_alphabetic()
+            && (bytes[1] == b':' || bytes[1] == b'|')
+            && (bytes.len() == 2 || bytes[2] == b'/')
+        {
+            s.push(bytes[0] as char);
+            s.push(':');
+            s.push_str(&without_root[2..]);
+
+            // `C:` alone is relative to the current directory of the drive
+            if bytes.len() == 2 {
+                s.push('\\');
+            }
+        } else {
+            s.push_str(&path);
+        }
+    } else {
+        s.push_str("\\\\");
+        s.push_str(&host);
+        s.push_str(&path);
+    }
+
+    Ok(s.replace('/', "\\"))
+}
+
+/// Convert a `file://` URI into an absolute path separated by `FILE_SEPARATOR`.
+///
+/// ```
+/// if cfg!(unix) {
+///     assert_eq!(
+///         Ok(String::from("/a b")),
+///         slash_formatter::file_uri_to_file_separator("file:///a%20b")
+///     );
+/// }
+/// ```
+#[inline]
+pub fn file_uri_to_file_separator<S: ?Sized + AsRef<str>>(uri: &S) -> Result<String, FileUriError> {
+    #[cfg(unix)]
+    {
+        file_uri_to_slash(uri)
+    }
+
+    #[cfg(windows)]
+    {
+        file_uri_to_backslash(uri)
+    }
+}
@@ -0,0 +1,517 @@
+use alloc::{string::String, vec::Vec};
+use core::{
+    fmt::{self, Display, Formatter},
+    marker::PhantomData,
+};
+
+use crate::{Backslash, FileSeparator, Separator, Slash};
+
+/// The maximum number of patterns which the alternations of a glob pattern can expand into.
+const MAX_EXPANSIONS: usize = 1024;
+
+/// Errors which can occur when parsing a glob pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GlobError {
+    /// A `{` at the byte offset is not closed.
+    UnclosedBrace { offset: usize },
+    /// A `[` at the byte offset is not closed.
+    UnclosedClass { offset: usize },
+    /// The alternations expand into more than 1024 patterns.
+    TooManyExpansions,
+}
+
+impl Display for GlobError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GlobError::UnclosedBrace {
+                offset,
+            } => write!(f, "the `{{` at {} is not closed", offset),
+            GlobError::UnclosedClass {
+                offset,
+            } => write!(f, "the `[` at {} is not closed", offset),
+            GlobError::TooManyExpansions => {
+                write!(f, "the alternations expand into more than {} patterns", MAX_EXPANSIONS)
+            },
+        }
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for GlobError {}
+
+#[derive(Debug, Clone)]
+enum Token {
+    Literal(char),
+    Separator,
+    /// `?`
+    Any,
+    /// `*`
+    Star,
+    /// `**` as a whole segment. `true` if it is followed by a separator.
+    GlobStar(bool),
+    /// `[...]`
+    Class {
+        negated: bool,
+        ranges:  Vec<(char, char)>,
+    },
+}
+
+#[derive(Debug)]
+enum Node {
+    Token(Token),
+    /// `{a,b}`
+    Alternation(Vec<Vec<Node>>),
+}
+
+struct Parser<'a> {
+    pattern:   &'a str,
+    position:  usize,
+    separator: char,
+}
+
+impl<'a> Parser<'a> {
+    #[inline]
+    fn peek(&self) -> Option<char> {
+        self.pattern[self.position..].chars().next()
+    }
+
+    #[inline]
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+
+        self.position += c.len_utf8();
+
+        Some(c)
+    }
+
+    fn parse_sequence(&mut self, in_brace: bool) -> Result<Vec<Node>, GlobError> {
+        let mut nodes = Vec::new();
+
+        while let Some(c) = self.peek() {
+            if in_brace && (c == ',' || c == '}') {
+                break;
+            }
+
+            let offset = self.position;
+
+            self.bump();
+
+            let token = match c {
+                '?' => Token::Any,
+                '*' => Token::Star,
+                '[' => self.parse_class(offset)?,
+                '{' => {
+                    nodes.push(self.parse_alternation(offset)?);
+
+                    continue;
+                },
+                c if c == self.separator => Token::Separator,
+                c => Token::Literal(c),
+            };
+
+            nodes.push(Node::Token(token));
+        }
+
+        Ok(nodes)
+    }
+
+    fn parse_alternation(&mut self, offset: usize) -> Result<Node, GlobError> {
+        let mut alternatives = Vec::new();
+
+        loop {
+            alternatives.push(self.parse_sequence(true)?);
+
+            match self.bump() {
+                Some(',') => (),
+                Some('}') => return Ok(Node::Alternation(alternatives)),
+                _ => {
+                    return Err(GlobError::UnclosedBrace {
+                        offset,
+                    })
+                },
+            }
+        }
+    }
+
+    fn parse_class(&mut self, offset: usize) -> Result<Token, GlobError> {
+        let negated = matches!(self.peek(), Some('!') | Some('^'));
+
+        if negated {
+            self.bump();
+        }
+
+        let mut ranges = Vec::new();
+        let mut first = true;
+
+        loop {
+            let c = match self.bump() {
+                Some(']') if !first => {
+                    return Ok(Token::Class {
+                        negated,
+                        ranges,
+                    })
+                },
+                Some(c) => c,
+                None => {
+                    return Err(GlobError::UnclosedClass {
+                        offset,
+                    })
+                },
+            };
+
+            first = false;
+
+            let rest = &self.pattern[self.position..];
+
+            let end = if rest.starts_with('-') && !rest.starts_with("-]") {
+                self.bump();
+                self.bump()
+            } else {
+                None
+            };
+
+            ranges.push((c, end.unwrap_or(c)));
+        }
+    }
+}
+
+/// Count the patterns which the alternations expand into, saturating instead of overflowing.
+fn count_expansions(nodes: &[Node]) -> usize {
+    nodes.iter().fold(1, |count, node| match node {
+        Node::Token(_) => count,
+        Node::Alternation(alternatives) => count.saturating_mul(
+            alternatives
+                .iter()
+                .fold(0, |sum, alternative| sum.saturating_add(count_expansions(alternative))),
+        ),
+    })
+}
+
+fn expand(nodes: &[Node]) -> Vec<Vec<Token>> {
+    let mut expansions = vec![Vec::new()];
+
+    for node in nodes {
+        match node {
+            Node::Token(token) => {
+                for expansion in expansions.iter_mut() {
+                    expansion.push(token.clone());
+                }
+            },
+            Node::Alternation(alternatives) => {
+                let mut new_expansions = Vec::new();
+
+                for expansion in expansions.iter() {
+                    for alternative in alternatives {
+                        for tail in expand(alternative) {
+                            let mut e = expansion.clone();
+
+                            e.extend(tail);
+
+                            new_expansions.push(e);
+                        }
+                    }
+                }
+
+                expansions = new_expansions;
+            },
+        }
+    }
+
+    expansions
+}
+
+/// Turn every `**` which forms a whole segment into a `GlobStar` token.
+fn find_glob_stars(tokens: Vec<Token>) -> Vec<Token> {
+    let mut output: Vec<Token> = Vec::with_capacity(tokens.len());
+    let mut iter = tokens.into_iter().peekable();
+
+    while let Some(token) = iter.next() {
+        if let Token::Star = token {
+            let at_segment_start = matches!(output.last(), None | Some(Token::Separator));
+
+            if at_segment_start && matches!(iter.peek(), Some(Token::Star)) {
+                iter.next();
+
+                // collapse `***` and more
+                while let Some(Token::Star) = iter.peek() {
+                    iter.next();
+                }
+
+                match iter.peek() {
+                    None => {
+                        output.push(Token::GlobStar(false));
+
+                        continue;
+                    },
+                    Some(Token::Separator) => {
+                        iter.next();
+
+                        output.push(Token::GlobStar(true));
+
+                        continue;
+                    },
+                    _ => output.push(Token::Star),
+                }
+            }
+        }
+
+        output.push(token);
+    }
+
+    output
+}
+
+struct Matcher<'a> {
+    tokens:    &'a [Token],
+    text:      &'a str,
+    separator: char,
+    /// Failed states, indexed by `token_index * (text.len() + 1) + text_index`.
+    failed:    Vec<bool>,
+}
+
+impl<'a> Matcher<'a> {
+    fn is_match(&mut self, ti: usize, xi: usize) -> bool {
+        let state = ti * (self.text.len() + 1) + xi;
+
+        if self.failed[state] {
+            return false;
+        }
+
+        let result = self.is_match_inner(ti, xi);
+
+        if !result {
+            self.failed[state] = true;
+        }
+
+        result
+    }
+
+    fn is_match_inner(&mut self, ti: usize, xi: usize) -> bool {
+        let text = &self.text[xi..];
+
+        let token = match self.tokens.get(ti) {
+            Some(token) => token,
+            None => return text.is_empty(),
+        };
+
+        let next = text.chars().next();
+
+        match token {
+            Token::Literal(c) => next == Some(*c) && self.is_match(ti + 1, xi + c.len_utf8()),
+            Token::Separator => {
+                next == Some(self.separator)
+                    && self.is_match(ti + 1, xi + self.separator.len_utf8())
+            },
+            Token::Any => match next {
+                Some(c) if c != self.separator => self.is_match(ti + 1, xi + c.len_utf8()),
+                _ => false,
+            },
+            Token::Class {
+                negated,
+                ranges,
+            } => match next {
+                Some(c) if c != self.separator => {
+                    let in_class = ranges.iter().any(|&(low, high)| low <= c && c <= high);
+
+                    in_class != *negated && self.is_match(ti + 1, xi + c.len_utf8())
+                },
+                _ => false,
+            },
+            Token::Star => {
+                let segment_length = text.find(self.separator).unwrap_or(text.len());
+
+                if self.is_match(ti + 1, xi + segment_length) {
+                    return true;
+                }
+
+                let boundaries: Vec<usize> =
+                    text[..segment_length].char_indices().map(|(i, _)| i).collect();
+
+                boundaries.into_iter().any(|i| self.is_match(ti + 1, xi + i))
+            },
+            Token::GlobStar(false) => true,
+            Token::GlobStar(true) => {
+                if self.is_match(ti + 1, xi) {
+                    return true;
+                }
+
+                let separator = self.separator;
+
+                let boundaries: Vec<usize> = text
+                    .char_indices()
+                    .filter(|&(_, c)| c == separator)
+                    .map(|(i, c)| i + c.len_utf8())
+                    .collect();
+
+                boundaries.into_iter().any(|i| self.is_match(ti + 1, xi + i))
+            },
+        }
+    }
+}
+
+/// A compiled glob pattern for strings separated by `S`.
+///
+/// * `?` matches any character except for the separator.
+/// * `*` matches any sequence of characters except for the separator.
+/// * `**` as a whole segment matches any number of segments, including none.
+/// * `[abc]`, `[a-z]`, `[!a-z]` and `[^a-z]` match one character, which is never the separator, in or not in the class.
+/// * `{a,b}` matches either of the alternatives, which can be nested.
+///
+/// An ending separator of the pattern or of a string to be matched is ignored, following the rules of `delete_end_*`.
+#[derive(Debug, Clone)]
+pub struct SeparatorGlob<S: Separator> {
+    pattern:      String,
+    alternatives: Vec<Vec<Token>>,
+    _separator:   PhantomData<S>,
+}
+
+/// A compiled glob pattern for strings separated by slashes.
+pub type SlashGlob = SeparatorGlob<Slash>;
+
+/// A compiled glob pattern for strings separated by backslashes.
+pub type BackslashGlob = SeparatorGlob<Backslash>;
+
+/// A compiled glob pattern for strings separated by `FILE_SEPARATOR`.
+pub type FileSeparatorGlob = SeparatorGlob<FileSeparator>;
+
+impl<S: Separator> SeparatorGlob<S> {
+    /// Compile a glob pattern. The alternations are expanded eagerly, so a pattern which expands into more than 1024 patterns is rejected.
+    ///
+    /// ```
+    /// use slash_formatter::{GlobError, SlashGlob};
+    ///
+    /// assert!(SlashGlob::new("assets/**/*.{png,jpg}").is_ok());
+    ///
+    /// assert_eq!(
+    ///     GlobError::UnclosedClass {
+    ///         offset: 2
+    ///     },
+    ///     SlashGlob::new("a/[b").unwrap_err()
+    /// );
+    ///
+    /// assert_eq!(
+    ///     GlobError::TooManyExpansions,
+    ///     SlashGlob::new(&"{a,b}".repeat(11)).unwrap_err()
+    /// );
+    /// ```
+    pub fn new<T: ?Sized + AsRef<str>>(pattern: &T) -> Result<Self, GlobError> {
+        let pattern = pattern.as_ref();
+
+        let mut parser = Parser {
+            pattern:   S::delete_end_separator(pattern),
+            position:  0,
+            separator: S::SEPARATOR,
+        };
+
+        let nodes = parser.parse_sequence(false)?;
+
+        if count_expansions(&nodes) > MAX_EXPANSIONS {
+            return Err(GlobError::TooManyExpansions);
+        }
+
+        let alternatives = expand(&nodes).into_iter().map(find_glob_stars).collect();
+
+        Ok(SeparatorGlob {
+            pattern: String::from(pattern),
+            alternatives,
+            _separator: PhantomData,
+        })
+    }
+
+    /// Get the source pattern.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Check whether a string matches this pattern.
+    ///
+    /// ```
+    /// use slash_formatter::{BackslashGlob, SlashGlob};
+    ///
+    /// let glob = SlashGlob::new("assets/**/*.png").unwrap();
+    ///
+    /// assert!(glob.is_match("assets/a.png"));
+    /// assert!(glob.is_match("assets/icons/x/a.png"));
+    /// assert!(!glob.is_match("assets/a.jpg"));
+    /// assert!(!glob.is_match("static/assets/a.png"));
+    ///
+    /// let glob = BackslashGlob::new("src\\*\\mod.rs").unwrap();
+    ///
+    /// assert!(glob.is_match("src\\glob\\mod.rs"));
+    /// assert!(!glob.is_match("src\\a\\b\\mod.rs"));
+    /// ```
+    pub fn is_match<T: ?Sized + AsRef<str>>(&self, s: &T) -> bool {
+        let text = S::delete_end_separator(s.as_ref());
+
+        self.alternatives.iter().any(|tokens| {
+            let mut matcher = Matcher {
+                tokens,
+                text,
+                separator: S::SEPARATOR,
+                failed: vec![false; (tokens.len() + 1) * (text.len() + 1)],
+            };
+
+            matcher.is_match(0, 0)
+        })
+    }
+}
+
+/// Check whether a string separated by slashes matches a glob pattern. See `SeparatorGlob` for the syntax.
+///
+/// ```
+/// assert_eq!(
+///     Ok(true),
+///     slash_formatter::glob_match_slash("src/{lib,main}.rs", "src/lib.rs")
+/// );
+/// assert_eq!(
+///     Ok(false),
+///     slash_formatter::glob_match_slash("src/?.rs", "src/ab.rs")
+/// );
+/// ```
+#[inline]
+pub fn glob_match_slash<P: ?Sized + AsRef<str>, T: ?Sized + AsRef<str>>(
+    pattern: &P,
+    s: &T,
+) -> Result<bool, GlobError> {
+    Ok(SlashGlob::new(pattern)?.is_match(s))
+}
+
+/// Check whether a string separated by backslashes matches a glob pattern. See `SeparatorGlob` for the syntax.
+///
+/// ```
+/// assert_eq!(
+///     Ok(true),
+///     slash_formatter::glob_match_backslash(
+///         "C:\\**\\[a-c]*.txt",
+///         "C:\\x\\y\\b1.txt"
+///     )
+/// );
+/// ```
+#[inline]
+pub fn glob_match_backslash<P: ?Sized + AsRef<str>, T: ?Sized + AsRef<str>>(
+    pattern: &P,
+    s: &T,
+) -> Result<bool, GlobError> {
+    Ok(BackslashGlob::new(pattern)?.is_match(s))
+}
+
+/// Check whether a string separated by `FILE_SEPARATOR` matches a glob pattern. See `SeparatorGlob` for the syntax.
+///
+/// ```
+/// assert_eq!(
+///     Ok(true),
+///     slash_formatter::glob_match_file_separator(
+///         slash_formatter::concat_with_file_separator!("src", "*.rs"),
+///         slash_formatter::concat_with_file_separator!("src", "lib.rs")
+///     )
+/// );
+/// ```
+#[inline]
+pub fn glob_match_file_separator<P: ?Sized + AsRef<str>, T: ?Sized + AsRef<str>>(
+    pattern: &P,
+    s: &T,
+) -> Result<bool, GlobError> {
+    Ok(FileSeparatorGlob::new(pattern)?.is_match(s))
+}
@@ -0,0 +1,157 @@
+use alloc::{borrow::Cow, string::String};
+
+use crate::{rebase::strip_base, Backslash, FileSeparator, Separator, Slash};
+
+fn expand_tilde<'a, S: Separator>(s: &'a str, home: &str) -> Cow<'a, str> {
+    let rest = match s.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with(S::SEPARATOR) => rest,
+        _ => return Cow::from(s),
+    };
+
+    let mut expanded = String::with_capacity(home.len() + rest.len());
+
+    expanded.push_str(home);
+
+    S::concat_with_separator_in_place(&mut expanded, rest);
+
+    Cow::from(expanded)
+}
+
+fn contract_home<'a, S: Separator>(s: &'a str, home: &str) -> Cow<'a, str> {
+    if S::delete_end_separator(home).is_empty() {
+        return Cow::from(s);
+    }
+
+    match strip_base::<S>(s, home) {
+        Some(rest) => {
+            let mut contracted = String::with_capacity(rest.len() + 2);
+
+            contracted.push('~');
+
+            if !rest.is_empty() {
+                S::concat_with_separator_in_place(&mut contracted, rest);
+            }
+
+            Cow::from(contracted)
+        },
+        None => Cow::from(s),
+    }
+}
+
+/// Replace a starting `~` followed by a slash or nothing with the home directory. The rest is joined with the rules of `concat_with_slash`.
+///
+/// ```
+/// assert_eq!(
+///     "/home/u/foo",
+///     slash_formatter::expand_tilde_slash("~/foo/", "/home/u")
+/// );
+/// assert_eq!("/home/u", slash_formatter::expand_tilde_slash("~", "/home/u/"));
+/// assert_eq!(
+///     "~u/foo",
+///     slash_formatter::expand_tilde_slash("~u/foo", "/home/u")
+/// );
+/// ```
+#[inline]
+pub fn expand_tilde_slash<'a, S1: ?Sized + AsRef<str>, S2: ?Sized + AsRef<str>>(
+    s: &'a S1,
+    home: &S2,
+) -> Cow<'a, str> {
+    expand_tilde::<Slash>(s.as_ref(), home.as_ref())
+}
+
+/// Replace a starting `~` followed by a backslash or nothing with the home directory. The rest is joined with the rules of `concat_with_backslash`.
+///
+/// ```
+/// assert_eq!(
+///     "C:\\Users\\u\\foo",
+///     slash_formatter::expand_tilde_backslash("~\\foo\\", "C:\\Users\\u")
+/// );
+/// ```
+#[inline]
+pub fn expand_tilde_backslash<'a, S1: ?Sized + AsRef<str>, S2: ?Sized + AsRef<str>>(
+    s: &'a S1,
+    home: &S2,
+) -> Cow<'a, str> {
+    expand_tilde::<Backslash>(s.as_ref(), home.as_ref())
+}
+
+/// Replace a starting `~` followed by `FILE_SEPARATOR` or nothing with the home directory. The rest is joined with the rules of `concat_with_file_separator`.
+///
+/// ```
+/// assert_eq!(
+///     slash_formatter::concat_with_file_separator!("home", "foo"),
+///     slash_formatter::expand_tilde_file_separator(
+///         slash_formatter::concat_with_file_separator!("~", "foo"),
+///         "home"
+///     )
+/// );
+/// ```
+#[inline]
+pub fn expand_tilde_file_separator<'a, S1: ?Sized + AsRef<str>, S2: ?Sized + AsRef<str>>(
+    s: &'a S1,
+    home: &S2,
+) -> Cow<'a, str> {
+    expand_tilde::<FileSeparator>(s.as_ref(), home.as_ref())
+}
+
+/// Replace the home directory at the start of a path separated by slashes with `~`. The home directory is matched segment by segment, and the rest is joined with the rules of `concat_with_slash`.
+///
+/// ```
+/// assert_eq!(
+///     "~/foo",
+///     slash_formatter::contract_home_slash("/home/u/foo/", "/home/u")
+/// );
+/// assert_eq!(
+///     "~",
+///     slash_formatter::contract_home_slash("/home/u", "/home/u/")
+/// );
+/// assert_eq!(
+///     "/home/user",
+///     slash_formatter::contract_home_slash("/home/user", "/home/u")
+/// );
+/// ```
+#[inline]
+pub fn contract_home_slash<'a, S1: ?Sized + AsRef<str>, S2: ?Sized + AsRef<str>>(
+    s: &'a S1,
+    home: &S2,
+) -> Cow<'a, str> {
+    contract_home::<Slash>(s.as_ref(), home.as_ref())
+}
+
+/// Replace the home directory at the start of a path separated by backslashes with `~`. The home directory is matched segment by segment, and the rest is joined with the rules of `concat_with_backslash`.
+///
+/// ```
+/// assert_eq!(
+///     "~\\foo",
+///     slash_formatter::contract_home_backslash(
+///         "C:\\Users\\u\\foo",
+///         "C:\\Users\\u"
+///     )
+/// );
+/// ```
+#[inline]
+pub fn contract_home_backslash<'a, S1: ?Sized + AsRef<str>, S2: ?Sized + AsRef<str>>(
+    s: &'a S1,
+    home: &S2,
+) -> Cow<'a, str> {
+    contract_home::<Backslash>(s.as_ref(), home.as_ref())
+}
+
+/// Replace the home directory at the start of a path separated by `FILE_SEPARATOR` with `~`. The home directory is matched segment by segment, and the rest is joined with the rules of `concat_with_file_separator`.
+///
+/// ```
+/// assert_eq!(
+///     slash_formatter::concat_with_file_separator!("~", "foo"),
+///     slash_formatter::contract_home_file_separator(
+///         slash_formatter::concat_with_file_separator!("home", "foo"),
+///         "home"
+///     )
+/// );
+/// ```
+#[inline]
+pub fn contract_home_file_separator<'a, S1: ?Sized + AsRef<str>, S2: ?Sized + AsRef<str>>(
+    s: &'a S1,
+    home: &S2,
+) -> Cow<'a, str> {
+    contract_home::<FileSeparator>(s.as_ref(), home.as_ref())
+}
@@ -0,0 +1,477 @@
+use alloc::{borrow::Cow, string::String};
+use core::{
+    fmt::{self, Display, Formatter},
+    iter::FusedIterator,
+    str::{FromStr, Split},
+};
+
+/// Errors which can occur when parsing a JSON pointer or a relative JSON pointer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonPointerError {
+    /// A slash is expected at the byte offset.
+    MissingStartSlash { offset: usize },
+    /// A `~` at the byte offset is not followed by `0` or `1`.
+    InvalidEscape { offset: usize },
+    /// The number of levels of a relative JSON pointer is invalid at the byte offset.
+    InvalidLevels { offset: usize },
+}
+
+impl Display for JsonPointerError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonPointerError::MissingStartSlash {
+                offset,
+            } => write!(f, "a slash is expected at {}", offset),
+            JsonPointerError::InvalidEscape {
+                offset,
+            } => write!(f, "an invalid escape at {}", offset),
+            JsonPointerError::InvalidLevels {
+                offset,
+            } => write!(f, "invalid levels at {}", offset),
+        }
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for JsonPointerError {}
+
+#[inline]
+fn escape_token(token: &str) -> Cow<'_, str> {
+    if token.contains(|c| c == '~' || c == '/') {
+        Cow::from(token.replace('~', "~0").replace('/', "~1"))
+    } else {
+        Cow::from(token)
+    }
+}
+
+#[inline]
+fn unescape_token(token: &str) -> Cow<'_, str> {
+    if token.contains('~') {
+        Cow::from(token.replace("~1", "/").replace("~0", "~"))
+    } else {
+        Cow::from(token)
+    }
+}
+
+/// Check that `s` is a valid JSON pointer. Offsets in the error are added by `base`.
+fn validate(s: &str, base: usize) -> Result<(), JsonPointerError> {
+    if !s.is_empty() && !s.starts_with('/') {
+        return Err(JsonPointerError::MissingStartSlash {
+            offset: base
+        });
+    }
+
+    let bytes = s.as_bytes();
+
+    for (i, b) in bytes.iter().enumerate() {
+        if *b == b'~' && !matches!(bytes.get(i + 1), Some(b'0') | Some(b'1')) {
+            return Err(JsonPointerError::InvalidEscape {
+                offset: base + i
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// A JSON pointer defined in RFC 6901, such as `/a~1b/0`. The empty pointer refers to the whole document.
+///
+/// Unlike `concat_with_slash`, empty reference tokens are kept, so `/` refers to the member whose key is empty.
+///
+/// ```
+/// use slash_formatter::JsonPointer;
+///
+/// let mut pointer = JsonPointer::new();
+///
+/// pointer.push("paths");
+/// pointer.push("/users/{id}");
+/// pointer.push("get");
+///
+/// assert_eq!("/paths/~1users~1{id}/get", pointer.as_str());
+///
+/// let tokens: Vec<_> = pointer.segments().collect();
+///
+/// assert_eq!(vec!["paths", "/users/{id}", "get"], tokens);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JsonPointer {
+    inner: String,
+}
+
+impl JsonPointer {
+    /// Create an empty `JsonPointer`, which refers to the whole document.
+    #[inline]
+    pub fn new() -> Self {
+        JsonPointer {
+            inner: String::new()
+        }
+    }
+
+    /// Parse a JSON pointer.
+    ///
+    /// ```
+    /// use slash_formatter::{JsonPointer, JsonPointerError};
+    ///
+    /// assert!(JsonPointer::parse("/a~0b/1").is_ok());
+    /// assert_eq!(
+    ///     Err(JsonPointerError::MissingStartSlash {
+    ///         offset: 0
+    ///     }),
+    ///     JsonPointer::parse("a")
+    /// );
+    /// assert_eq!(
+    ///     Err(JsonPointerError::InvalidEscape {
+    ///         offset: 2
+    ///     }),
+    ///     JsonPointer::parse("/a~2")
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse<S: ?Sized + AsRef<str>>(s: &S) -> Result<Self, JsonPointerError> {
+        let s = s.as_ref();
+
+        validate(s, 0)?;
+
+        Ok(JsonPointer {
+            inner: String::from(s)
+        })
+    }
+
+    /// Extract a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.inner
+    }
+
+    /// Convert into a `String`.
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.inner
+    }
+
+    /// Whether this pointer refers to the whole document.
+    #[inline]
+    pub fn is_root(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Create an iterator over the unescaped reference tokens.
+    ///
+    /// ```
+    /// use slash_formatter::JsonPointer;
+    ///
+    /// let pointer = JsonPointer::parse("/a~1b//c~0").unwrap();
+    ///
+    /// let tokens: Vec<_> = pointer.segments().collect();
+    ///
+    /// assert_eq!(vec!["a/b", "", "c~"], tokens);
+    /// ```
+    #[inline]
+    pub fn segments(&self) -> JsonPointerSegments<'_> {
+        JsonPointerSegments {
+            split: self.inner.get(1..).map(|s| s.split('/'))
+        }
+    }
+
+    /// Get the pointer without its last reference token, or `None` if it refers to the whole document.
+    ///
+    /// ```
+    /// use slash_formatter::JsonPointer;
+    ///
+    /// let pointer = JsonPointer::parse("/a/b").unwrap();
+    ///
+    /// assert_eq!("/a", pointer.parent().unwrap().as_str());
+    /// assert_eq!("", pointer.parent().unwrap().parent().unwrap().as_str());
+    /// assert_eq!(None, JsonPointer::new().parent());
+    /// ```
+    #[inline]
+    pub fn parent(&self) -> Option<JsonPointer> {
+        self.inner.rfind('/').map(|index| JsonPointer {
+            inner: String::from(&self.inner[..index])
+        })
+    }
+
+    /// Create a new pointer with a reference token appended. The token is escaped.
+    ///
+    /// ```
+    /// use slash_formatter::JsonPointer;
+    ///
+    /// let pointer = JsonPointer::parse("/a").unwrap();
+    ///
+    /// assert_eq!("/a/m~0n", pointer.join("m~n").as_str());
+    /// ```
+    #[inline]
+    pub fn join<T: AsRef<str>>(&self, token: T) -> JsonPointer {
+        let mut pointer = self.clone();
+
+        pointer.push(token);
+
+        pointer
+    }
+
+    /// Append a reference token. The token is escaped.
+    ///
+    /// ```
+    /// use slash_formatter::JsonPointer;
+    ///
+    /// let mut pointer = JsonPointer::new();
+    ///
+    /// pointer.push("a/b");
+    /// pointer.push("");
+    ///
+    /// assert_eq!("/a~1b/", pointer.as_str());
+    /// ```
+    #[inline]
+    pub fn push<T: AsRef<str>>(&mut self, token: T) {
+        self.inner.push('/');
+        self.inner.push_str(&escape_token(token.as_ref()));
+    }
+
+    /// Remove the last reference token. Returns `false` if the pointer refers to the whole document.
+    ///
+    /// ```
+    /// use slash_formatter::JsonPointer;
+    ///
+    /// let mut pointer = JsonPointer::parse("/a/b").unwrap();
+    ///
+    /// assert!(pointer.pop());
+    /// assert_eq!("/a", pointer.as_str());
+    /// assert!(pointer.pop());
+    /// assert!(!pointer.pop());
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> bool {
+        match self.inner.rfind('/') {
+            Some(index) => {
+                self.inner.truncate(index);
+
+                true
+            },
+            None => false,
+        }
+    }
+}
+
+impl AsRef<str> for JsonPointer {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.inner
+    }
+}
+
+impl Display for JsonPointer {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.inner)
+    }
+}
+
+impl FromStr for JsonPointer {
+    type Err = JsonPointerError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl From<JsonPointer> for String {
+    #[inline]
+    fn from(pointer: JsonPointer) -> Self {
+        pointer.inner
+    }
+}
+
+impl<T: AsRef<str>> FromIterator<T> for JsonPointer {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut pointer = JsonPointer::new();
+
+        pointer.extend(iter);
+
+        pointer
+    }
+}
+
+impl<T: AsRef<str>> Extend<T> for JsonPointer {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for token in iter {
+            self.push(token);
+        }
+    }
+}
+
+/// An iterator over the unescaped reference tokens of a `JsonPointer`.
+#[derive(Debug, Clone)]
+pub struct JsonPointerSegments<'a> {
+    split: Option<Split<'a, char>>,
+}
+
+impl<'a> Iterator for JsonPointerSegments<'a> {
+    type Item = Cow<'a, str>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.split.as_mut()?.next().map(unescape_token)
+    }
+}
+
+impl<'a> DoubleEndedIterator for JsonPointerSegments<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.split.as_mut()?.next_back().map(unescape_token)
+    }
+}
+
+impl<'a> FusedIterator for JsonPointerSegments<'a> {}
+
+/// A relative JSON pointer, such as `1/a` or `2#`, which goes up a number of levels from the current location and then follows a JSON pointer, or refers to the key of the location with `#`.
+///
+/// ```
+/// use slash_formatter::{JsonPointer, RelativeJsonPointer};
+///
+/// let current = JsonPointer::parse("/users/0/name").unwrap();
+///
+/// let relative = RelativeJsonPointer::parse("1/email").unwrap();
+///
+/// assert_eq!("/users/0/email", relative.resolve(&current).unwrap().as_str());
+///
+/// let relative = RelativeJsonPointer::parse("1#").unwrap();
+///
+/// assert!(relative.is_key_reference());
+/// assert_eq!("/users/0", relative.resolve(&current).unwrap().as_str());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RelativeJsonPointer {
+    levels:  usize,
+    pointer: Option<JsonPointer>,
+}
+
+impl RelativeJsonPointer {
+    /// Create a relative JSON pointer which goes up `levels` levels and then follows `pointer`.
+    #[inline]
+    pub fn new(levels: usize, pointer: JsonPointer) -> Self {
+        RelativeJsonPointer {
+            levels,
+            pointer: Some(pointer),
+        }
+    }
+
+    /// Create a relative JSON pointer which goes up `levels` levels and then refers to the key, written with `#`.
+    #[inline]
+    pub fn key_reference(levels: usize) -> Self {
+        RelativeJsonPointer {
+            levels,
+            pointer: None,
+        }
+    }
+
+    /// Parse a relative JSON pointer.
+    ///
+    /// ```
+    /// use slash_formatter::{JsonPointerError, RelativeJsonPointer};
+    ///
+    /// assert!(RelativeJsonPointer::parse("0").is_ok());
+    /// assert_eq!(
+    ///     Err(JsonPointerError::InvalidLevels {
+    ///         offset: 1
+    ///     }),
+    ///     RelativeJsonPointer::parse("01/a")
+    /// );
+    /// assert_eq!(
+    ///     Err(JsonPointerError::MissingStartSlash {
+    ///         offset: 1
+    ///     }),
+    ///     RelativeJsonPointer::parse("1a")
+    /// );
+    /// ```
+    pub fn parse<S: ?Sized + AsRef<str>>(s: &S) -> Result<Self, JsonPointerError> {
+        let s = s.as_ref();
+
+        let digits = s.bytes().take_while(u8::is_ascii_digit).count();
+
+        if digits == 0 {
+            return Err(JsonPointerError::InvalidLevels {
+                offset: 0
+            });
+        }
+
+        if digits > 1 && s.starts_with('0') {
+            return Err(JsonPointerError::InvalidLevels {
+                offset: 1
+            });
+        }
+
+        let levels = s[..digits].parse().map_err(|_| JsonPointerError::InvalidLevels {
+            offset: 0,
+        })?;
+
+        let rest = &s[digits..];
+
+        if rest == "#" {
+            return Ok(Self::key_reference(levels));
+        }
+
+        validate(rest, digits)?;
+
+        Ok(Self::new(levels, JsonPointer {
+            inner: String::from(rest)
+        }))
+    }
+
+    /// The number of levels to go up.
+    #[inline]
+    pub fn levels(&self) -> usize {
+        self.levels
+    }
+
+    /// The JSON pointer to follow, or `None` if this refers to the key with `#`.
+    #[inline]
+    pub fn pointer(&self) -> Option<&JsonPointer> {
+        self.pointer.as_ref()
+    }
+
+    /// Whether this refers to the key of the location with `#`.
+    #[inline]
+    pub fn is_key_reference(&self) -> bool {
+        self.pointer.is_none()
+    }
+
+    /// Resolve this relative JSON pointer against the current location. For a key reference, the result is the location whose key is referred to. Returns `None` if it goes up beyond the whole document.
+    pub fn resolve(&self, current: &JsonPointer) -> Option<JsonPointer> {
+        let mut resolved = current.clone();
+
+        for _ in 0..self.levels {
+            if !resolved.pop() {
+                return None;
+            }
+        }
+
+        if let Some(pointer) = self.pointer.as_ref() {
+            resolved.inner.push_str(&pointer.inner);
+        }
+
+        Some(resolved)
+    }
+}
+
+impl Display for RelativeJsonPointer {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.pointer.as_ref() {
+            Some(pointer) => write!(f, "{}{}", self.levels, pointer),
+            None => write!(f, "{}#", self.levels),
+        }
+    }
+}
+
+impl FromStr for RelativeJsonPointer {
+    type Err = JsonPointerError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
@@ -13,14 +13,71 @@ To see examples, check out the documentation for each function.
 #[macro_use]
 extern crate alloc;
 
+mod any_separator;
 mod backslash;
+mod bytes;
+mod case_fold;
+mod common_prefix;
+mod confusable;
+mod env;
+mod escape;
 mod file_separator;
 mod file_separator_build;
+mod file_uri;
+mod glob;
+mod home;
+mod json_pointer;
+mod mqtt;
+mod namespace;
+mod path;
+mod path_list_separator;
+mod path_list_separator_build;
+mod path_tree;
+mod percent;
+mod rebase;
+mod safe_join;
+mod sanitize;
+mod separator;
+mod separator_buf;
 mod slash;
+mod template;
+mod trie;
+mod units;
+mod validate;
+mod wide;
+mod windows;
 
+#[cfg(feature = "serde")]
+pub mod serde;
+
+pub use any_separator::*;
 pub use backslash::*;
+pub use bytes::*;
+pub use common_prefix::*;
 #[doc(hidden)]
 pub use concat_with::{concat, concat_impl};
+pub use confusable::*;
+pub use env::*;
+pub use escape::*;
 pub use file_separator::*;
 pub use file_separator_build::*;
+pub use file_uri::*;
+pub use glob::*;
+pub use home::*;
+pub use json_pointer::*;
+pub use mqtt::*;
+pub use namespace::*;
+pub use path::*;
+pub use path_list_separator::*;
+pub use path_list_separator_build::*;
+pub use path_tree::*;
+pub use rebase::*;
+pub use safe_join::*;
+pub use sanitize::*;
+pub use separator::*;
+pub use separator_buf::*;
 pub use slash::*;
+pub use template::*;
+pub use trie::*;
+pub use validate::*;
+pub use wide::*;
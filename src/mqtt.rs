@@ -0,0 +1,263 @@
+use alloc::string::String;
+use core::{
+    fmt::{self, Display, Formatter},
+    str::Split,
+};
+
+use crate::SeparatorBuf;
+
+/// The maximum length of a topic name or a topic filter in bytes.
+const MAX_LENGTH: usize = 65535;
+
+/// Errors which can occur when validating an MQTT topic name or an MQTT topic filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MqttTopicError {
+    /// The topic is empty.
+    Empty,
+    /// The topic is longer than 65535 bytes.
+    TooLong { length: usize },
+    /// A null character at the byte offset.
+    NullCharacter { offset: usize },
+    /// A wildcard character in a topic name at the byte offset.
+    WildcardInName { offset: usize },
+    /// A wildcard character at the byte offset which does not occupy an entire level, or a `#` which is not the last level.
+    InvalidWildcard { offset: usize },
+}
+
+impl Display for MqttTopicError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MqttTopicError::Empty => f.write_str("the topic is empty"),
+            MqttTopicError::TooLong {
+                length,
+            } => write!(f, "the topic is too long ({} > {})", length, MAX_LENGTH),
+            MqttTopicError::NullCharacter {
+                offset,
+            } => write!(f, "a null character at {}", offset),
+            MqttTopicError::WildcardInName {
+                offset,
+            } => write!(f, "a wildcard character in a topic name at {}", offset),
+            MqttTopicError::InvalidWildcard {
+                offset,
+            } => write!(f, "an invalid wildcard at {}", offset),
+        }
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for MqttTopicError {}
+
+fn validate_common(s: &str) -> Result<(), MqttTopicError> {
+    if s.is_empty() {
+        return Err(MqttTopicError::Empty);
+    }
+
+    if s.len() > MAX_LENGTH {
+        return Err(MqttTopicError::TooLong {
+            length: s.len()
+        });
+    }
+
+    match s.find('\0') {
+        Some(offset) => Err(MqttTopicError::NullCharacter {
+            offset,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Check whether a string is a valid MQTT topic name, which is used to publish messages. Wildcards are not allowed, and empty levels are valid.
+///
+/// ```
+/// use slash_formatter::MqttTopicError;
+///
+/// assert_eq!(
+///     Ok(()),
+///     slash_formatter::validate_mqtt_topic_name("/finance//stock")
+/// );
+/// assert_eq!(
+///     Err(MqttTopicError::WildcardInName {
+///         offset: 6
+///     }),
+///     slash_formatter::validate_mqtt_topic_name("sport/+")
+/// );
+/// ```
+pub fn validate_mqtt_topic_name<S: ?Sized + AsRef<str>>(s: &S) -> Result<(), MqttTopicError> {
+    let s = s.as_ref();
+
+    validate_common(s)?;
+
+    match s.find(|c| c == '+' || c == '#') {
+        Some(offset) => Err(MqttTopicError::WildcardInName {
+            offset,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Check whether a string is a valid MQTT topic filter, which is used to subscribe. `+` must occupy an entire level, and `#` must occupy the last level.
+///
+/// ```
+/// use slash_formatter::MqttTopicError;
+///
+/// assert_eq!(
+///     Ok(()),
+///     slash_formatter::validate_mqtt_topic_filter("sport/+/player1/#")
+/// );
+/// assert_eq!(
+///     Err(MqttTopicError::InvalidWildcard {
+///         offset: 5
+///     }),
+///     slash_formatter::validate_mqtt_topic_filter("sport+")
+/// );
+/// assert_eq!(
+///     Err(MqttTopicError::InvalidWildcard {
+///         offset: 6
+///     }),
+///     slash_formatter::validate_mqtt_topic_filter("sport/#/ranking")
+/// );
+/// ```
+pub fn validate_mqtt_topic_filter<S: ?Sized + AsRef<str>>(s: &S) -> Result<(), MqttTopicError> {
+    let s = s.as_ref();
+
+    validate_common(s)?;
+
+    let mut offset = 0;
+
+    for level in s.split('/') {
+        let end = offset + level.len();
+
+        match level {
+            "+" => (),
+            "#" if end == s.len() => (),
+            _ => {
+                if let Some(index) = level.find(|c| c == '+' || c == '#') {
+                    return Err(MqttTopicError::InvalidWildcard {
+                        offset: offset + index
+                    });
+                }
+            },
+        }
+
+        offset = end + 1;
+    }
+
+    Ok(())
+}
+
+/// Create an iterator over the levels of an MQTT topic. Unlike `slash_segments`, every slash delimits a level, so empty levels at the start and the end are yielded.
+///
+/// ```
+/// let levels: Vec<&str> =
+///     slash_formatter::mqtt_topic_levels("/finance/").collect();
+///
+/// assert_eq!(vec!["", "finance", ""], levels);
+/// ```
+#[inline]
+pub fn mqtt_topic_levels<S: ?Sized + AsRef<str>>(s: &S) -> Split<'_, char> {
+    s.as_ref().split('/')
+}
+
+/// Concatenate an MQTT topic and a level with a slash. Unlike `concat_with_slash`, no slash is deleted, so empty levels are preserved.
+///
+/// ```
+/// assert_eq!(
+///     "sport/",
+///     slash_formatter::concat_with_mqtt_topic_level("sport", "")
+/// );
+/// assert_eq!(
+///     "/finance",
+///     slash_formatter::concat_with_mqtt_topic_level("", "finance")
+/// );
+/// ```
+#[inline]
+pub fn concat_with_mqtt_topic_level<S1: Into<String>, S2: AsRef<str>>(s1: S1, s2: S2) -> String {
+    let mut s1 = s1.into();
+
+    concat_with_mqtt_topic_level_in_place(&mut s1, s2);
+
+    s1
+}
+
+/// Concatenate an MQTT topic and a level with a slash. Unlike `concat_with_slash`, no slash is deleted, so empty levels are preserved.
+///
+/// ```
+/// let mut s = String::from("sport/");
+///
+/// slash_formatter::concat_with_mqtt_topic_level_in_place(&mut s, "tennis");
+///
+/// assert_eq!("sport//tennis", s);
+/// ```
+#[inline]
+pub fn concat_with_mqtt_topic_level_in_place<B: SeparatorBuf, S2: AsRef<str>>(s1: &mut B, s2: S2) {
+    s1.push('/');
+    s1.push_str(s2.as_ref());
+}
+
+/// Join levels into an MQTT topic with slashes. Empty levels are preserved.
+///
+/// ```
+/// assert_eq!(
+///     "/finance//",
+///     slash_formatter::join_mqtt_topic_levels(["", "finance", "", ""])
+/// );
+/// ```
+pub fn join_mqtt_topic_levels<I: IntoIterator<Item = S>, S: AsRef<str>>(levels: I) -> String {
+    let mut joined = String::new();
+
+    for (i, level) in levels.into_iter().enumerate() {
+        if i > 0 {
+            joined.push('/');
+        }
+
+        joined.push_str(level.as_ref());
+    }
+
+    joined
+}
+
+/// Check whether an MQTT topic name matches an MQTT topic filter. `+` matches exactly one level, and `#` matches the parent level and any number of child levels. Filters starting with a wildcard do not match topic names starting with `$`, such as `$SYS/broker`. An invalid filter or topic name never matches.
+///
+/// ```
+/// assert!(slash_formatter::mqtt_topic_matches(
+///     "sport/tennis/+",
+///     "sport/tennis/player1"
+/// ));
+/// assert!(slash_formatter::mqtt_topic_matches("sport/#", "sport"));
+/// assert!(slash_formatter::mqtt_topic_matches("+/+", "/finance"));
+/// assert!(!slash_formatter::mqtt_topic_matches(
+///     "sport/+",
+///     "sport/tennis/player1"
+/// ));
+/// assert!(!slash_formatter::mqtt_topic_matches("#", "$SYS/broker/load"));
+/// assert!(slash_formatter::mqtt_topic_matches("$SYS/#", "$SYS/broker/load"));
+/// ```
+pub fn mqtt_topic_matches<F: ?Sized + AsRef<str>, T: ?Sized + AsRef<str>>(
+    filter: &F,
+    topic: &T,
+) -> bool {
+    let filter = filter.as_ref();
+    let topic = topic.as_ref();
+
+    if validate_mqtt_topic_filter(filter).is_err() || validate_mqtt_topic_name(topic).is_err() {
+        return false;
+    }
+
+    if topic.starts_with('$') && filter.starts_with(|c| c == '+' || c == '#') {
+        return false;
+    }
+
+    let mut filter_levels = filter.split('/');
+    let mut topic_levels = topic.split('/');
+
+    loop {
+        match (filter_levels.next(), topic_levels.next()) {
+            (Some("#"), _) => return true,
+            (Some("+"), Some(_)) => (),
+            (Some(f), Some(t)) if f == t => (),
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
@@ -0,0 +1,339 @@
+use alloc::string::String;
+
+use crate::{rebase::strip_base, Backslash, FileSeparator, Segments, Separator, Slash};
+
+/// Options for converting between namespaces, such as `com.example.Foo` or `crate::a::b`, and paths. All of them are empty by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NamespaceOptions<'a> {
+    /// A leading namespace which is removed when converting into a path and added back when converting from a path, such as `crate`.
+    pub namespace_prefix: &'a str,
+    /// A base path which the path is under, such as `src`.
+    pub path_prefix:      &'a str,
+    /// A suffix appended to the last segment of the path before the extension, such as `_test`.
+    pub suffix:           &'a str,
+    /// An extension without the dot, such as `class` or `rs`.
+    pub extension:        &'a str,
+}
+
+/// Whether a segment can be used both as a segment of a namespace and as a segment of a path, without changing the meaning of either.
+#[inline]
+fn is_valid_segment<S: Separator>(segment: &str, delimiter: &str) -> bool {
+    !(matches!(segment, "." | "..")
+        || segment.contains(S::SEPARATOR)
+        || (S::SEPARATOR == '\\' && segment.contains('/'))
+        || segment.contains(delimiter))
+}
+
+fn namespace_to_path<S: Separator>(
+    namespace: &str,
+    delimiter: &str,
+    options: &NamespaceOptions,
+) -> Option<String> {
+    let namespace = if options.namespace_prefix.is_empty() {
+        namespace
+    } else if namespace == options.namespace_prefix {
+        ""
+    } else {
+        namespace
+            .strip_prefix(options.namespace_prefix)
+            .and_then(|rest| rest.strip_prefix(delimiter))
+            .unwrap_or(namespace)
+    };
+
+    let mut path = String::from(options.path_prefix);
+    let mut has_segment = false;
+
+    for segment in namespace.split(delimiter).filter(|segment| !segment.is_empty()) {
+        if !is_valid_segment::<S>(segment, delimiter) {
+            return None;
+        }
+
+        if path.is_empty() {
+            path.push_str(segment);
+        } else {
+            S::concat_with_separator_in_place(&mut path, segment);
+        }
+
+        has_segment = true;
+    }
+
+    if has_segment {
+        path.push_str(options.suffix);
+
+        if !options.extension.is_empty() {
+            path.push('.');
+            path.push_str(options.extension);
+        }
+    } else {
+        let length = S::delete_end_separator(&path).len();
+
+        path.truncate(length);
+    }
+
+    Some(path)
+}
+
+fn path_to_namespace<S: Separator>(
+    path: &str,
+    delimiter: &str,
+    options: &NamespaceOptions,
+) -> Option<String> {
+    let mut rest = strip_base::<S>(path, options.path_prefix)?;
+
+    rest = S::delete_end_separator(rest);
+
+    if !rest.is_empty() && rest != S::SEPARATOR_STR {
+        if !options.extension.is_empty() {
+            rest = rest.strip_suffix(options.extension).and_then(|rest| rest.strip_suffix('.'))?;
+        }
+
+        rest = rest.strip_suffix(options.suffix)?;
+
+        // the file stem must not be empty, such as `src/a/.rs`
+        if rest.is_empty() || rest.ends_with(S::SEPARATOR) {
+            return None;
+        }
+    }
+
+    let mut namespace = String::from(options.namespace_prefix);
+
+    for segment in Segments::<S>::new(rest).filter(|segment| !segment.is_empty()) {
+        if !is_valid_segment::<S>(segment, delimiter) {
+            return None;
+        }
+
+        if !namespace.is_empty() {
+            namespace.push_str(delimiter);
+        }
+
+        namespace.push_str(segment);
+    }
+
+    Some(namespace)
+}
+
+/// Convert a namespace delimited by `delimiter`, such as `.` or `::`, into a path separated by slashes. The path prefix is joined with the rules of `concat_with_slash`, and empty segments are skipped. Returns `None` if a segment is `.` or `..`, or contains a slash, so that the path cannot leave the path prefix.
+///
+/// ```
+/// use slash_formatter::NamespaceOptions;
+///
+/// assert_eq!(
+///     Some(String::from("com/example/Foo.class")),
+///     slash_formatter::namespace_to_slash(
+///         "com.example.Foo",
+///         ".",
+///         &NamespaceOptions {
+///             extension: "class",
+///             ..Default::default()
+///         }
+///     )
+/// );
+///
+/// let options = NamespaceOptions {
+///     namespace_prefix: "crate",
+///     path_prefix: "src/",
+///     extension: "rs",
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(
+///     Some(String::from("src/a/b.rs")),
+///     slash_formatter::namespace_to_slash("crate::a::b", "::", &options)
+/// );
+/// assert_eq!(
+///     Some(String::from("src")),
+///     slash_formatter::namespace_to_slash("crate", "::", &options)
+/// );
+/// assert_eq!(
+///     None,
+///     slash_formatter::namespace_to_slash(
+///         "crate::a::..::..::etc",
+///         "::",
+///         &options
+///     )
+/// );
+/// assert_eq!(
+///     None,
+///     slash_formatter::namespace_to_slash(
+///         "a/b",
+///         ".",
+///         &NamespaceOptions::default()
+///     )
+/// );
+/// ```
+#[inline]
+pub fn namespace_to_slash<S: ?Sized + AsRef<str>>(
+    namespace: &S,
+    delimiter: &str,
+    options: &NamespaceOptions,
+) -> Option<String> {
+    namespace_to_path::<Slash>(namespace.as_ref(), delimiter, options)
+}
+
+/// Convert a namespace delimited by `delimiter`, such as `.` or `::`, into a path separated by backslashes. The path prefix is joined with the rules of `concat_with_backslash`, and empty segments are skipped. Returns `None` if a segment is `.` or `..`, or contains a backslash or a slash, so that the path cannot leave the path prefix.
+///
+/// ```
+/// use slash_formatter::NamespaceOptions;
+///
+/// assert_eq!(
+///     Some(String::from("C:\\src\\a\\b_test.rs")),
+///     slash_formatter::namespace_to_backslash(
+///         "a::b",
+///         "::",
+///         &NamespaceOptions {
+///             path_prefix: "C:\\src\\",
+///             suffix: "_test",
+///             extension: "rs",
+///             ..Default::default()
+///         }
+///     )
+/// );
+/// ```
+#[inline]
+pub fn namespace_to_backslash<S: ?Sized + AsRef<str>>(
+    namespace: &S,
+    delimiter: &str,
+    options: &NamespaceOptions,
+) -> Option<String> {
+    namespace_to_path::<Backslash>(namespace.as_ref(), delimiter, options)
+}
+
+/// Convert a namespace delimited by `delimiter`, such as `.` or `::`, into a path separated by `FILE_SEPARATOR`. The path prefix is joined with the rules of `concat_with_file_separator`, and empty segments are skipped. Returns `None` if a segment is `.` or `..`, or contains `FILE_SEPARATOR`, so that the path cannot leave the path prefix.
+///
+/// ```
+/// use slash_formatter::NamespaceOptions;
+///
+/// assert_eq!(
+///     Some(String::from(slash_formatter::concat_with_file_separator!(
+///         "com", "example"
+///     ))),
+///     slash_formatter::namespace_to_file_separator(
+///         "com.example",
+///         ".",
+///         &NamespaceOptions::default()
+///     )
+/// );
+/// ```
+#[inline]
+pub fn namespace_to_file_separator<S: ?Sized + AsRef<str>>(
+    namespace: &S,
+    delimiter: &str,
+    options: &NamespaceOptions,
+) -> Option<String> {
+    namespace_to_path::<FileSeparator>(namespace.as_ref(), delimiter, options)
+}
+
+/// Convert a path separated by slashes into a namespace delimited by `delimiter`, such as `.` or `::`. This is the inverse of `namespace_to_slash`. Returns `None` if the path is not under the path prefix, does not end with the suffix and the extension, has an empty file stem, or has a segment which cannot be converted back, such as `..` or one containing `delimiter`.
+///
+/// ```
+/// use slash_formatter::NamespaceOptions;
+///
+/// let options = NamespaceOptions {
+///     namespace_prefix: "crate",
+///     path_prefix: "src",
+///     extension: "rs",
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(
+///     Some(String::from("crate::a::b")),
+///     slash_formatter::slash_to_namespace("src/a/b.rs", "::", &options)
+/// );
+/// assert_eq!(
+///     None,
+///     slash_formatter::slash_to_namespace("tests/a.rs", "::", &options)
+/// );
+/// assert_eq!(
+///     None,
+///     slash_formatter::slash_to_namespace("src/a.txt", "::", &options)
+/// );
+/// assert_eq!(
+///     None,
+///     slash_formatter::slash_to_namespace("src/a/.rs", "::", &options)
+/// );
+///
+/// let namespace =
+///     slash_formatter::slash_to_namespace("src/a/b.rs", "::", &options)
+///         .unwrap();
+///
+/// assert_eq!(
+///     Some(String::from("src/a/b.rs")),
+///     slash_formatter::namespace_to_slash(&namespace, "::", &options)
+/// );
+///
+/// let options = NamespaceOptions {
+///     extension: "class",
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(
+///     None,
+///     slash_formatter::slash_to_namespace("a.b/c.class", ".", &options)
+/// );
+///
+/// let path =
+///     slash_formatter::namespace_to_slash("a.b.c", ".", &options).unwrap();
+///
+/// assert_eq!(
+///     Some(String::from("a.b.c")),
+///     slash_formatter::slash_to_namespace(&path, ".", &options)
+/// );
+/// ```
+#[inline]
+pub fn slash_to_namespace<S: ?Sized + AsRef<str>>(
+    path: &S,
+    delimiter: &str,
+    options: &NamespaceOptions,
+) -> Option<String> {
+    path_to_namespace::<Slash>(path.as_ref(), delimiter, options)
+}
+
+/// Convert a path separated by backslashes into a namespace delimited by `delimiter`, such as `.` or `::`. This is the inverse of `namespace_to_backslash`. Returns `None` if the path is not under the path prefix, does not end with the suffix and the extension, has an empty file stem, or has a segment which cannot be converted back, such as `..` or one containing `delimiter`.
+///
+/// ```
+/// use slash_formatter::NamespaceOptions;
+///
+/// assert_eq!(
+///     Some(String::from("com.example.Foo")),
+///     slash_formatter::backslash_to_namespace(
+///         "classes\\com\\example\\Foo.class",
+///         ".",
+///         &NamespaceOptions {
+///             path_prefix: "classes",
+///             extension: "class",
+///             ..Default::default()
+///         }
+///     )
+/// );
+/// ```
+#[inline]
+pub fn backslash_to_namespace<S: ?Sized + AsRef<str>>(
+    path: &S,
+    delimiter: &str,
+    options: &NamespaceOptions,
+) -> Option<String> {
+    path_to_namespace::<Backslash>(path.as_ref(), delimiter, options)
+}
+
+/// Convert a path separated by `FILE_SEPARATOR` into a namespace delimited by `delimiter`, such as `.` or `::`. This is the inverse of `namespace_to_file_separator`. Returns `None` if the path is not under the path prefix, does not end with the suffix and the extension, has an empty file stem, or has a segment which cannot be converted back, such as `..` or one containing `delimiter`.
+///
+/// ```
+/// use slash_formatter::NamespaceOptions;
+///
+/// assert_eq!(
+///     Some(String::from("com.example")),
+///     slash_formatter::file_separator_to_namespace(
+///         slash_formatter::concat_with_file_separator!("com", "example"),
+///         ".",
+///         &NamespaceOptions::default()
+///     )
+/// );
+/// ```
+#[inline]
+pub fn file_separator_to_namespace<S: ?Sized + AsRef<str>>(
+    path: &S,
+    delimiter: &str,
+    options: &NamespaceOptions,
+) -> Option<String> {
+    path_to_namespace::<FileSeparator>(path.as_ref(), delimiter, options)
+}
@@ -0,0 +1,527 @@
+use alloc::{borrow::ToOwned, string::String};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{self, Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::Deref,
+};
+
+use crate::{Backslash, FileSeparator, Segments, Separator, Slash};
+
+/// Delete all ending separators in a string except for just the separator.
+#[inline]
+fn trim_end<S: Separator>(mut s: &str) -> &str {
+    loop {
+        let t = S::delete_end_separator(s);
+
+        if t.len() == s.len() {
+            return s;
+        }
+
+        s = t;
+    }
+}
+
+/// A borrowed string separated by `S` which never ends with the separator, except for just the separator.
+#[repr(transparent)]
+pub struct SeparatorPath<S: Separator> {
+    _separator: PhantomData<S>,
+    inner:      str,
+}
+
+/// A borrowed string separated by slashes which never ends with a slash, except for `"/"`.
+pub type SlashPath = SeparatorPath<Slash>;
+
+/// A borrowed string separated by backslashes which never ends with a backslash, except for `"\\"`.
+pub type BackslashPath = SeparatorPath<Backslash>;
+
+/// A borrowed string separated by `FILE_SEPARATOR` which never ends with `FILE_SEPARATOR`, except for just `FILE_SEPARATOR`.
+pub type FileSeparatorPath = SeparatorPath<FileSeparator>;
+
+impl<S: Separator> SeparatorPath<S> {
+    #[inline]
+    fn from_str_unchecked(s: &str) -> &Self {
+        // SAFETY: `SeparatorPath<S>` is `repr(transparent)` over `str`
+        unsafe { &*(s as *const str as *const SeparatorPath<S>) }
+    }
+
+    /// Create a `SeparatorPath` by deleting ending separators of a string.
+    ///
+    /// ```
+    /// use slash_formatter::SlashPath;
+    ///
+    /// assert_eq!("path/to", SlashPath::new("path/to//").as_str());
+    /// assert_eq!("/", SlashPath::new("/").as_str());
+    /// ```
+    #[inline]
+    pub fn new<T: ?Sized + AsRef<str>>(s: &T) -> &Self {
+        Self::from_str_unchecked(trim_end::<S>(s.as_ref()))
+    }
+
+    /// Extract a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.inner
+    }
+
+    /// Create an iterator over the segments.
+    ///
+    /// ```
+    /// use slash_formatter::SlashPath;
+    ///
+    /// let segments: Vec<&str> = SlashPath::new("/path/to").segments().collect();
+    ///
+    /// assert_eq!(vec!["path", "to"], segments);
+    /// ```
+    #[inline]
+    pub fn segments(&self) -> Segments<'_, S> {
+        Segments::new(&self.inner)
+    }
+
+    /// Get the path without its last segment, or `None` if there is no segment.
+    ///
+    /// ```
+    /// use slash_formatter::SlashPath;
+    ///
+    /// assert_eq!("/path", SlashPath::new("/path/to").parent().unwrap().as_str());
+    /// assert_eq!("/", SlashPath::new("/path").parent().unwrap().as_str());
+    /// assert_eq!("", SlashPath::new("path").parent().unwrap().as_str());
+    /// assert!(SlashPath::new("/").parent().is_none());
+    /// ```
+    #[inline]
+    pub fn parent(&self) -> Option<&Self> {
+        let s = &self.inner;
+
+        if s.is_empty() || s == S::SEPARATOR_STR {
+            return None;
+        }
+
+        let parent = match s.rfind(S::SEPARATOR) {
+            Some(0) => S::SEPARATOR_STR,
+            Some(index) => trim_end::<S>(&s[..index]),
+            None => "",
+        };
+
+        Some(Self::from_str_unchecked(parent))
+    }
+
+    /// Concatenate this path and a string with the separator into a new `SeparatorPathBuf`.
+    ///
+    /// ```
+    /// use slash_formatter::SlashPath;
+    ///
+    /// assert_eq!(
+    ///     "path/to/file",
+    ///     SlashPath::new("path").join("/to/file/").as_str()
+    /// );
+    /// ```
+    #[inline]
+    pub fn join<T: AsRef<str>>(&self, s: T) -> SeparatorPathBuf<S> {
+        let mut path = self.to_path_buf();
+
+        path.push(s);
+
+        path
+    }
+
+    /// Create an owned `SeparatorPathBuf`.
+    #[inline]
+    pub fn to_path_buf(&self) -> SeparatorPathBuf<S> {
+        SeparatorPathBuf::from_string_unchecked(String::from(&self.inner))
+    }
+}
+
+impl<S: Separator> Deref for SeparatorPath<S> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        &self.inner
+    }
+}
+
+impl<S: Separator> AsRef<str> for SeparatorPath<S> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.inner
+    }
+}
+
+impl<S: Separator> Debug for SeparatorPath<S> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.inner, f)
+    }
+}
+
+impl<S: Separator> Display for SeparatorPath<S> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.inner, f)
+    }
+}
+
+impl<S: Separator> PartialEq for SeparatorPath<S> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<S: Separator> Eq for SeparatorPath<S> {}
+
+impl<S: Separator> PartialOrd for SeparatorPath<S> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: Separator> Ord for SeparatorPath<S> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl<S: Separator> Hash for SeparatorPath<S> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state)
+    }
+}
+
+impl<S: Separator> ToOwned for SeparatorPath<S> {
+    type Owned = SeparatorPathBuf<S>;
+
+    #[inline]
+    fn to_owned(&self) -> SeparatorPathBuf<S> {
+        self.to_path_buf()
+    }
+}
+
+impl<'a, S: Separator> From<&'a str> for &'a SeparatorPath<S> {
+    #[inline]
+    fn from(s: &'a str) -> Self {
+        SeparatorPath::new(s)
+    }
+}
+
+/// An owned string separated by `S` which never ends with the separator, except for just the separator.
+pub struct SeparatorPathBuf<S: Separator> {
+    _separator: PhantomData<S>,
+    inner:      String,
+}
+
+/// An owned string separated by slashes which never ends with a slash, except for `"/"`.
+pub type SlashPathBuf = SeparatorPathBuf<Slash>;
+
+/// An owned string separated by backslashes which never ends with a backslash, except for `"\\"`.
+pub type BackslashPathBuf = SeparatorPathBuf<Backslash>;
+
+/// An owned string separated by `FILE_SEPARATOR` which never ends with `FILE_SEPARATOR`, except for just `FILE_SEPARATOR`.
+pub type FileSeparatorPathBuf = SeparatorPathBuf<FileSeparator>;
+
+impl<S: Separator> SeparatorPathBuf<S> {
+    #[inline]
+    fn from_string_unchecked(inner: String) -> Self {
+        SeparatorPathBuf {
+            _separator: PhantomData,
+            inner,
+        }
+    }
+
+    /// Create an empty `SeparatorPathBuf`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::from_string_unchecked(String::new())
+    }
+
+    /// Coerce to a `SeparatorPath` slice.
+    #[inline]
+    pub fn as_path(&self) -> &SeparatorPath<S> {
+        SeparatorPath::from_str_unchecked(&self.inner)
+    }
+
+    /// Extract a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.inner
+    }
+
+    /// Convert into a `String`.
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.inner
+    }
+
+    /// Create an iterator over the segments.
+    ///
+    /// ```
+    /// use slash_formatter::SlashPathBuf;
+    ///
+    /// let path = SlashPathBuf::from("/path/to/");
+    ///
+    /// let segments: Vec<&str> = path.segments().collect();
+    ///
+    /// assert_eq!(vec!["path", "to"], segments);
+    /// ```
+    #[inline]
+    pub fn segments(&self) -> Segments<'_, S> {
+        Segments::new(&self.inner)
+    }
+
+    /// Get the path without its last segment, or `None` if there is no segment.
+    ///
+    /// ```
+    /// use slash_formatter::SlashPathBuf;
+    ///
+    /// let path = SlashPathBuf::from("/path/to");
+    ///
+    /// assert_eq!("/path", path.parent().unwrap().as_str());
+    /// ```
+    #[inline]
+    pub fn parent(&self) -> Option<&SeparatorPath<S>> {
+        self.as_path().parent()
+    }
+
+    /// Concatenate this path and a string with the separator into a new `SeparatorPathBuf`.
+    ///
+    /// ```
+    /// use slash_formatter::BackslashPathBuf;
+    ///
+    /// let path = BackslashPathBuf::from("C:\\");
+    ///
+    /// assert_eq!("C:\\Users", path.join("Users\\").as_str());
+    /// ```
+    #[inline]
+    pub fn join<T: AsRef<str>>(&self, s: T) -> SeparatorPathBuf<S> {
+        self.as_path().join(s)
+    }
+
+    /// Concatenate a string to the end of this path with the separator, following the rules of `concat_with_*`. Pushing onto an empty path does not add a starting separator.
+    ///
+    /// ```
+    /// use slash_formatter::SlashPathBuf;
+    ///
+    /// let mut path = SlashPathBuf::new();
+    ///
+    /// path.push("path/");
+    /// path.push("/to//");
+    ///
+    /// assert_eq!("path/to", path.as_str());
+    /// ```
+    #[inline]
+    pub fn push<T: AsRef<str>>(&mut self, s: T) {
+        let s = s.as_ref();
+
+        if self.inner.is_empty() {
+            self.inner.push_str(trim_end::<S>(s));
+        } else {
+            S::concat_with_separator_in_place(&mut self.inner, s);
+
+            let length = trim_end::<S>(&self.inner).len();
+
+            self.inner.truncate(length);
+        }
+    }
+
+    /// Remove the last segment. Returns `false` if there is no segment.
+    ///
+    /// ```
+    /// use slash_formatter::SlashPathBuf;
+    ///
+    /// let mut path = SlashPathBuf::from("/path/to");
+    ///
+    /// assert!(path.pop());
+    /// assert_eq!("/path", path.as_str());
+    /// assert!(path.pop());
+    /// assert_eq!("/", path.as_str());
+    /// assert!(!path.pop());
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> bool {
+        match self.parent().map(|parent| parent.len()) {
+            Some(length) => {
+                self.inner.truncate(length);
+
+                true
+            },
+            None => false,
+        }
+    }
+}
+
+impl<S: Separator> Default for SeparatorPathBuf<S> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Separator> Clone for SeparatorPathBuf<S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::from_string_unchecked(self.inner.clone())
+    }
+}
+
+impl<S: Separator> Deref for SeparatorPathBuf<S> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        &self.inner
+    }
+}
+
+impl<S: Separator> AsRef<str> for SeparatorPathBuf<S> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.inner
+    }
+}
+
+impl<S: Separator> AsRef<SeparatorPath<S>> for SeparatorPathBuf<S> {
+    #[inline]
+    fn as_ref(&self) -> &SeparatorPath<S> {
+        self.as_path()
+    }
+}
+
+impl<S: Separator> Borrow<SeparatorPath<S>> for SeparatorPathBuf<S> {
+    #[inline]
+    fn borrow(&self) -> &SeparatorPath<S> {
+        self.as_path()
+    }
+}
+
+impl<S: Separator> Debug for SeparatorPathBuf<S> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.inner, f)
+    }
+}
+
+impl<S: Separator> Display for SeparatorPathBuf<S> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.inner, f)
+    }
+}
+
+impl<S: Separator> PartialEq for SeparatorPathBuf<S> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<S: Separator> Eq for SeparatorPathBuf<S> {}
+
+impl<S: Separator> PartialOrd for SeparatorPathBuf<S> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: Separator> Ord for SeparatorPathBuf<S> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl<S: Separator> Hash for SeparatorPathBuf<S> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state)
+    }
+}
+
+impl<S: Separator> From<String> for SeparatorPathBuf<S> {
+    /// Delete ending separators of the string and wrap it.
+    ///
+    /// ```
+    /// use slash_formatter::SlashPathBuf;
+    ///
+    /// let path = SlashPathBuf::from(String::from("path/to/"));
+    ///
+    /// assert_eq!("path/to", path.as_str());
+    /// ```
+    #[inline]
+    fn from(mut s: String) -> Self {
+        let length = trim_end::<S>(&s).len();
+
+        s.truncate(length);
+
+        Self::from_string_unchecked(s)
+    }
+}
+
+impl<S: Separator> From<&str> for SeparatorPathBuf<S> {
+    #[inline]
+    fn from(s: &str) -> Self {
+        SeparatorPath::<S>::new(s).to_path_buf()
+    }
+}
+
+impl<S: Separator> From<&SeparatorPath<S>> for SeparatorPathBuf<S> {
+    #[inline]
+    fn from(path: &SeparatorPath<S>) -> Self {
+        path.to_path_buf()
+    }
+}
+
+impl<S: Separator> From<SeparatorPathBuf<S>> for String {
+    #[inline]
+    fn from(path: SeparatorPathBuf<S>) -> Self {
+        path.inner
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<S: Separator> ::serde::Serialize for SeparatorPath<S> {
+    #[inline]
+    fn serialize<Se: ::serde::Serializer>(&self, serializer: Se) -> Result<Se::Ok, Se::Error> {
+        serializer.serialize_str(&self.inner)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<S: Separator> ::serde::Serialize for SeparatorPathBuf<S> {
+    #[inline]
+    fn serialize<Se: ::serde::Serializer>(&self, serializer: Se) -> Result<Se::Ok, Se::Error> {
+        serializer.serialize_str(&self.inner)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S: Separator> ::serde::Deserialize<'de> for SeparatorPathBuf<S> {
+    /// Deserialize a string and delete its ending separators.
+    ///
+    /// ```
+    /// use serde::{
+    ///     de::{
+    ///         value::{Error, StrDeserializer},
+    ///         IntoDeserializer,
+    ///     },
+    ///     Deserialize,
+    /// };
+    /// use slash_formatter::SlashPathBuf;
+    ///
+    /// let deserializer: StrDeserializer<'_, Error> =
+    ///     "path/to/".into_deserializer();
+    ///
+    /// let path = SlashPathBuf::deserialize(deserializer).unwrap();
+    ///
+    /// assert_eq!("path/to", path.as_str());
+    /// ```
+    #[inline]
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <String as ::serde::Deserialize>::deserialize(deserializer).map(Self::from)
+    }
+}
@@ -0,0 +1,327 @@
+use alloc::{borrow::Cow, string::String};
+use core::{
+    fmt::{self, Display, Formatter},
+    iter::FusedIterator,
+};
+
+/// Errors which can occur when joining paths into a path list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PathListError {
+    /// The path at the index contains the path-list separator, which cannot be escaped.
+    SeparatorInPath { index: usize },
+    /// The path at the index contains a `"`, which cannot be escaped in a Windows path list.
+    QuoteInPath { index: usize },
+    /// The path at the index is empty, which would be lost or read as the current directory after splitting.
+    EmptyPath { index: usize },
+}
+
+impl Display for PathListError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PathListError::SeparatorInPath {
+                index,
+            } => write!(f, "the path at {} contains the path-list separator", index),
+            PathListError::QuoteInPath {
+                index,
+            } => write!(f, "the path at {} contains a double quote", index),
+            PathListError::EmptyPath {
+                index,
+            } => write!(f, "the path at {} is empty", index),
+        }
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for PathListError {}
+
+/// An iterator over the paths in a path list, such as the value of `PATH`.
+#[derive(Debug, Clone)]
+pub struct PathListSplit<'a> {
+    remaining: Option<&'a str>,
+    separator: char,
+    quotes:    bool,
+}
+
+impl<'a> Iterator for PathListSplit<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let s = self.remaining?;
+
+        let mut in_quotes = false;
+        let mut quoted = false;
+        let mut end = None;
+
+        for (index, c) in s.char_indices() {
+            if self.quotes && c == '"' {
+                in_quotes = !in_quotes;
+                quoted = true;
+            } else if c == self.separator && !in_quotes {
+                end = Some(index);
+
+                break;
+            }
+        }
+
+        let path = match end {
+            Some(end) => {
+                self.remaining = Some(&s[end + self.separator.len_utf8()..]);
+
+                &s[..end]
+            },
+            None => {
+                self.remaining = None;
+
+                s
+            },
+        };
+
+        if quoted {
+            Some(Cow::from(path.replace('"', "")))
+        } else {
+            Some(Cow::from(path))
+        }
+    }
+}
+
+impl<'a> FusedIterator for PathListSplit<'a> {}
+
+#[inline]
+fn split(s: &str, separator: char, quotes: bool) -> PathListSplit<'_> {
+    PathListSplit {
+        remaining: if s.is_empty() { None } else { Some(s) },
+        separator,
+        quotes,
+    }
+}
+
+/// Split a path list separated by colons, like `PATH` on Unix. Empty paths are kept, except that an empty string has no paths.
+///
+/// ```
+/// let paths: Vec<_> =
+///     slash_formatter::split_path_list_unix("/usr/bin::/bin").collect();
+///
+/// assert_eq!(vec!["/usr/bin", "", "/bin"], paths);
+/// ```
+#[inline]
+pub fn split_path_list_unix<S: ?Sized + AsRef<str>>(s: &S) -> PathListSplit<'_> {
+    split(s.as_ref(), ':', false)
+}
+
+/// Split a path list separated by semicolons, like `PATH` on Windows. Double quotes are removed, and semicolons inside them do not separate paths. Empty paths are kept, except that an empty string has no paths.
+///
+/// ```
+/// let paths: Vec<_> =
+///     slash_formatter::split_path_list_windows("C:\\bin;\"C:\\a;b\";D:\\")
+///         .collect();
+///
+/// assert_eq!(vec!["C:\\bin", "C:\\a;b", "D:\\"], paths);
+/// ```
+#[inline]
+pub fn split_path_list_windows<S: ?Sized + AsRef<str>>(s: &S) -> PathListSplit<'_> {
+    split(s.as_ref(), ';', true)
+}
+
+/// Split a path list separated by `PATH_LIST_SEPARATOR`.
+///
+/// ```
+/// let list = slash_formatter::concat_with_path_list_separator!("a", "b");
+///
+/// let paths: Vec<_> = slash_formatter::split_path_list(list).collect();
+///
+/// assert_eq!(vec!["a", "b"], paths);
+/// ```
+#[inline]
+pub fn split_path_list<S: ?Sized + AsRef<str>>(s: &S) -> PathListSplit<'_> {
+    #[cfg(unix)]
+    {
+        split_path_list_unix(s)
+    }
+
+    #[cfg(windows)]
+    {
+        split_path_list_windows(s)
+    }
+}
+
+/// Join paths into a path list separated by colons, like `PATH` on Unix. Empty paths are rejected.
+///
+/// ```
+/// use slash_formatter::PathListError;
+///
+/// assert_eq!(
+///     Ok(String::from("/usr/bin:/bin")),
+///     slash_formatter::join_path_list_unix(["/usr/bin", "/bin"])
+/// );
+///
+/// assert_eq!(
+///     Err(PathListError::SeparatorInPath {
+///         index: 1
+///     }),
+///     slash_formatter::join_path_list_unix(["/usr/bin", "/a:b"])
+/// );
+///
+/// assert_eq!(
+///     Err(PathListError::EmptyPath {
+///         index: 0
+///     }),
+///     slash_formatter::join_path_list_unix([""])
+/// );
+/// ```
+pub fn join_path_list_unix<I: IntoIterator<Item = S>, S: AsRef<str>>(
+    paths: I,
+) -> Result<String, PathListError> {
+    let mut s = String::new();
+
+    for (index, path) in paths.into_iter().enumerate() {
+        let path = path.as_ref();
+
+        if path.is_empty() {
+            return Err(PathListError::EmptyPath {
+                index,
+            });
+        }
+
+        if path.contains(':') {
+            return Err(PathListError::SeparatorInPath {
+                index,
+            });
+        }
+
+        if index > 0 {
+            s.push(':');
+        }
+
+        s.push_str(path);
+    }
+
+    Ok(s)
+}
+
+/// Join paths into a path list separated by semicolons, like `PATH` on Windows. A path which contains semicolons is wrapped in double quotes. Empty paths are rejected.
+///
+/// ```
+/// use slash_formatter::PathListError;
+///
+/// assert_eq!(
+///     Ok(String::from("C:\\bin;\"C:\\a;b\"")),
+///     slash_formatter::join_path_list_windows(["C:\\bin", "C:\\a;b"])
+/// );
+///
+/// assert_eq!(
+///     Err(PathListError::EmptyPath {
+///         index: 1
+///     }),
+///     slash_formatter::join_path_list_windows(["C:\\bin", ""])
+/// );
+/// ```
+pub fn join_path_list_windows<I: IntoIterator<Item = S>, S: AsRef<str>>(
+    paths: I,
+) -> Result<String, PathListError> {
+    let mut s = String::new();
+
+    for (index, path) in paths.into_iter().enumerate() {
+        let path = path.as_ref();
+
+        if path.is_empty() {
+            return Err(PathListError::EmptyPath {
+                index,
+            });
+        }
+
+        if path.contains('"') {
+            return Err(PathListError::QuoteInPath {
+                index,
+            });
+        }
+
+        if index > 0 {
+            s.push(';');
+        }
+
+        if path.contains(';') {
+            s.push('"');
+            s.push_str(path);
+            s.push('"');
+        } else {
+            s.push_str(path);
+        }
+    }
+
+    Ok(s)
+}
+
+/// Join paths into a path list separated by `PATH_LIST_SEPARATOR`. Empty paths are rejected.
+///
+/// ```
+/// assert_eq!(
+///     Ok(String::from(slash_formatter::concat_with_path_list_separator!(
+///         "a", "b"
+///     ))),
+///     slash_formatter::join_path_list(["a", "b"])
+/// );
+/// ```
+#[inline]
+pub fn join_path_list<I: IntoIterator<Item = S>, S: AsRef<str>>(
+    paths: I,
+) -> Result<String, PathListError> {
+    #[cfg(unix)]
+    {
+        join_path_list_unix(paths)
+    }
+
+    #[cfg(windows)]
+    {
+        join_path_list_windows(paths)
+    }
+}
+
+#[cfg(unix)]
+/**
+Get the literal `PATH_LIST_SEPARATOR`, which separates paths in `PATH`.
+
+```
+assert_eq!("a:b", concat!("a", slash_formatter::path_list_separator!(), "b"));
+```
+*/
+#[macro_export]
+macro_rules! path_list_separator {
+    () => {
+        ':'
+    };
+}
+
+#[cfg(windows)]
+/**
+Get the literal `PATH_LIST_SEPARATOR`, which separates paths in `PATH`.
+
+```
+assert_eq!("a;b", concat!("a", slash_formatter::path_list_separator!(), "b"));
+```
+*/
+#[macro_export]
+macro_rules! path_list_separator {
+    () => {
+        ';'
+    };
+}
+
+concat_with::concat_impl! {
+    #[cfg(unix)]
+    #[macro_export]
+    /// Concatenates literals into a static string slice separated by `PATH_LIST_SEPARATOR`. Prefixes and suffixes can also be added.
+    ///
+    /// ```rust
+    /// assert_eq!(concat!("/usr/bin", slash_formatter::path_list_separator!(), "/bin"), slash_formatter::concat_with_path_list_separator!("/usr/bin", "/bin"));
+    /// ```
+    concat_with_path_list_separator => ":",
+    #[cfg(windows)]
+    #[macro_export]
+    /// Concatenates literals into a static string slice separated by `PATH_LIST_SEPARATOR`. Prefixes and suffixes can also be added.
+    ///
+    /// ```rust
+    /// assert_eq!(concat!("C:\\bin", slash_formatter::path_list_separator!(), "D:\\bin"), slash_formatter::concat_with_path_list_separator!("C:\\bin", "D:\\bin"));
+    /// ```
+    concat_with_path_list_separator => ";",
+}
@@ -0,0 +1,100 @@
+use alloc::string::String;
+
+use crate::{PathListError, PathListSplit};
+
+/// Split a path list separated by ``PATH_LIST_SEPARATOR_ON_WORKSTATION``.
+///
+/// ```
+/// let list =
+///     slash_formatter::concat_with_path_list_separator_build!("a", "b");
+///
+/// let paths: Vec<_> = slash_formatter::split_path_list_build(list).collect();
+///
+/// assert_eq!(vec!["a", "b"], paths);
+/// ```
+#[inline]
+pub fn split_path_list_build<S: ?Sized + AsRef<str>>(s: &S) -> PathListSplit<'_> {
+    #[cfg(from_unix)]
+    {
+        crate::split_path_list_unix(s)
+    }
+
+    #[cfg(from_windows)]
+    {
+        crate::split_path_list_windows(s)
+    }
+}
+
+/// Join paths into a path list separated by ``PATH_LIST_SEPARATOR_ON_WORKSTATION``. Empty paths are rejected.
+///
+/// ```
+/// assert_eq!(
+///     Ok(String::from(
+///         slash_formatter::concat_with_path_list_separator_build!("a", "b")
+///     )),
+///     slash_formatter::join_path_list_build(["a", "b"])
+/// );
+/// ```
+#[inline]
+pub fn join_path_list_build<I: IntoIterator<Item = S>, S: AsRef<str>>(
+    paths: I,
+) -> Result<String, PathListError> {
+    #[cfg(from_unix)]
+    {
+        crate::join_path_list_unix(paths)
+    }
+
+    #[cfg(from_windows)]
+    {
+        crate::join_path_list_windows(paths)
+    }
+}
+
+#[cfg(from_unix)]
+/**
+Get the literal ``PATH_LIST_SEPARATOR_ON_WORKSTATION``.
+
+```
+assert_eq!(concat!("a", slash_formatter::path_list_separator_build!(), "b"), slash_formatter::concat_with_path_list_separator_build!("a", "b"));
+```
+*/
+#[macro_export]
+macro_rules! path_list_separator_build {
+    () => {
+        ':'
+    };
+}
+
+#[cfg(from_windows)]
+/**
+Get the literal ``PATH_LIST_SEPARATOR_ON_WORKSTATION``.
+
+```
+assert_eq!(concat!("a", slash_formatter::path_list_separator_build!(), "b"), slash_formatter::concat_with_path_list_separator_build!("a", "b"));
+```
+*/
+#[macro_export]
+macro_rules! path_list_separator_build {
+    () => {
+        ';'
+    };
+}
+
+concat_with::concat_impl! {
+    #[cfg(from_unix)]
+    #[macro_export]
+    /// Concatenates literals into a static string slice separated by ``PATH_LIST_SEPARATOR_ON_WORKSTATION``. Prefixes and suffixes can also be added.
+    ///
+    /// ```rust
+    /// assert_eq!(concat!("a", slash_formatter::path_list_separator_build!(), "b"), slash_formatter::concat_with_path_list_separator_build!("a", "b"));
+    /// ```
+    concat_with_path_list_separator_build => ":",
+    #[cfg(from_windows)]
+    #[macro_export]
+    /// Concatenates literals into a static string slice separated by ``PATH_LIST_SEPARATOR_ON_WORKSTATION``. Prefixes and suffixes can also be added.
+    ///
+    /// ```rust
+    /// assert_eq!(concat!("a", slash_formatter::path_list_separator_build!(), "b"), slash_formatter::concat_with_path_list_separator_build!("a", "b"));
+    /// ```
+    concat_with_path_list_separator_build => ";",
+}
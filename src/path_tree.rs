@@ -0,0 +1,233 @@
+use alloc::{collections::BTreeMap, string::String};
+use core::fmt::{self, Display, Formatter};
+
+use crate::{Backslash, FileSeparator, Segments, Separator, Slash};
+
+/// A node of a `PathTree`, which is a directory or a file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PathTreeNode {
+    dir:      bool,
+    children: BTreeMap<String, PathTreeNode>,
+}
+
+impl PathTreeNode {
+    /// Check whether this node is a directory. A node is a directory if its path is given with an ending separator or if it has children.
+    #[inline]
+    pub fn is_dir(&self) -> bool {
+        self.dir
+    }
+
+    /// Check whether this node is a file.
+    #[inline]
+    pub fn is_file(&self) -> bool {
+        !self.dir
+    }
+
+    /// Check whether this node has no children.
+    #[inline]
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Get a child by its name.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&PathTreeNode> {
+        self.children.get(name)
+    }
+
+    /// Create an iterator over the names and the children, sorted by names.
+    #[inline]
+    pub fn children(&self) -> impl Iterator<Item = (&str, &PathTreeNode)> {
+        self.children.iter().map(|(name, child)| (name.as_str(), child))
+    }
+
+    fn fmt_children(&self, f: &mut Formatter<'_>, prefix: &mut String) -> fmt::Result {
+        let count = self.children.len();
+
+        for (index, (name, child)) in self.children.iter().enumerate() {
+            let last = index + 1 == count;
+
+            f.write_str("\n")?;
+            f.write_str(prefix)?;
+            f.write_str(if last { "└── " } else { "├── " })?;
+            f.write_str(name)?;
+
+            if child.dir {
+                f.write_str("/")?;
+            }
+
+            let length = prefix.len();
+
+            prefix.push_str(if last { "    " } else { "│   " });
+
+            child.fmt_children(f, prefix)?;
+
+            prefix.truncate(length);
+        }
+
+        Ok(())
+    }
+}
+
+/// A hierarchical tree built from a flat list of paths, such as object keys, archive entries or file listings.
+///
+/// Paths are split with `Segments`, so a starting separator does not matter, and empty segments are skipped. A path with an ending separator is a directory, and its ancestors are directories too.
+///
+/// The `Display` implementation prints the tree like the `tree -F` command.
+///
+/// ```
+/// use slash_formatter::PathTree;
+///
+/// let tree = PathTree::from_slash_paths(["src/lib.rs", "src/path/", "Cargo.toml", "src/main.rs"]);
+///
+/// assert_eq!(
+///     ".\n├── Cargo.toml\n└── src/\n    ├── lib.rs\n    ├── main.rs\n    └── path/",
+///     tree.to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PathTree {
+    root: PathTreeNode,
+}
+
+impl PathTree {
+    /// Create an empty tree.
+    #[inline]
+    pub fn new() -> Self {
+        PathTree {
+            root: PathTreeNode {
+                dir: true, children: BTreeMap::new()
+            },
+        }
+    }
+
+    /// Get the root node, which is always a directory.
+    #[inline]
+    pub fn root(&self) -> &PathTreeNode {
+        &self.root
+    }
+
+    /// Check whether this tree has no nodes except the root.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.root.is_leaf()
+    }
+
+    fn insert<S: Separator>(&mut self, path: &str) {
+        let mut node = &mut self.root;
+        let mut segments =
+            Segments::<S>::new(path).filter(|segment| !segment.is_empty()).peekable();
+
+        while let Some(segment) = segments.next() {
+            node.dir = true;
+
+            node = node.children.entry(String::from(segment)).or_default();
+
+            if segments.peek().is_none() && path.ends_with(S::SEPARATOR) {
+                node.dir = true;
+            }
+        }
+    }
+
+    /// Insert a path separated by slashes.
+    ///
+    /// ```
+    /// use slash_formatter::PathTree;
+    ///
+    /// let mut tree = PathTree::new();
+    ///
+    /// tree.insert_slash("/a/b");
+    /// tree.insert_slash("/a/c/");
+    ///
+    /// let a = tree.root().get("a").unwrap();
+    ///
+    /// assert!(a.is_dir());
+    /// assert!(a.get("b").unwrap().is_file());
+    /// assert!(a.get("c").unwrap().is_dir());
+    /// ```
+    #[inline]
+    pub fn insert_slash<S: ?Sized + AsRef<str>>(&mut self, path: &S) {
+        self.insert::<Slash>(path.as_ref())
+    }
+
+    /// Insert a path separated by backslashes.
+    ///
+    /// ```
+    /// use slash_formatter::PathTree;
+    ///
+    /// let mut tree = PathTree::new();
+    ///
+    /// tree.insert_backslash("a\\b\\");
+    ///
+    /// assert!(tree.root().get("a").unwrap().get("b").unwrap().is_dir());
+    /// ```
+    #[inline]
+    pub fn insert_backslash<S: ?Sized + AsRef<str>>(&mut self, path: &S) {
+        self.insert::<Backslash>(path.as_ref())
+    }
+
+    /// Insert a path separated by `FILE_SEPARATOR`.
+    #[inline]
+    pub fn insert_file_separator<S: ?Sized + AsRef<str>>(&mut self, path: &S) {
+        self.insert::<FileSeparator>(path.as_ref())
+    }
+
+    /// Build a tree from paths separated by slashes.
+    #[inline]
+    pub fn from_slash_paths<I: IntoIterator<Item = S>, S: AsRef<str>>(paths: I) -> Self {
+        let mut tree = Self::new();
+
+        for path in paths {
+            tree.insert_slash(&path);
+        }
+
+        tree
+    }
+
+    /// Build a tree from paths separated by backslashes.
+    ///
+    /// ```
+    /// use slash_formatter::PathTree;
+    ///
+    /// let tree = PathTree::from_backslash_paths(["a\\b", "a\\c\\d"]);
+    ///
+    /// assert_eq!(".\n└── a/\n    ├── b\n    └── c/\n        └── d", tree.to_string());
+    /// ```
+    #[inline]
+    pub fn from_backslash_paths<I: IntoIterator<Item = S>, S: AsRef<str>>(paths: I) -> Self {
+        let mut tree = Self::new();
+
+        for path in paths {
+            tree.insert_backslash(&path);
+        }
+
+        tree
+    }
+
+    /// Build a tree from paths separated by `FILE_SEPARATOR`.
+    #[inline]
+    pub fn from_file_separator_paths<I: IntoIterator<Item = S>, S: AsRef<str>>(paths: I) -> Self {
+        let mut tree = Self::new();
+
+        for path in paths {
+            tree.insert_file_separator(&path);
+        }
+
+        tree
+    }
+}
+
+impl Default for PathTree {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for PathTree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(".")?;
+
+        self.root.fmt_children(f, &mut String::new())
+    }
+}
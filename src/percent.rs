@@ -0,0 +1,121 @@
+use alloc::{borrow::Cow, string::String, vec::Vec};
+
+const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Push a character into a string percent-encoded, byte by byte of its UTF-8 form.
+#[inline]
+pub(crate) fn push_encoded_char(s: &mut String, c: char) {
+    let mut buffer = [0; 4];
+
+    for &b in c.encode_utf8(&mut buffer).as_bytes() {
+        s.push('%');
+        s.push(HEX[(b >> 4) as usize] as char);
+        s.push(HEX[(b & 0xF) as usize] as char);
+    }
+}
+
+/// Push a string into another string, percent-encoding every character which is not allowed in a path segment of a URI.
+#[inline]
+pub(crate) fn push_encoded_segment(s: &mut String, segment: &str) {
+    for c in segment.chars() {
+        if is_pchar(c) {
+            s.push(c);
+        } else {
+            push_encoded_char(s, c);
+        }
+    }
+}
+
+/// Check whether a character can be used in a path segment of a URI without being percent-encoded. See RFC 3986.
+#[inline]
+pub(crate) fn is_pchar(c: char) -> bool {
+    c.is_ascii_alphanumeric()
+        || matches!(
+            c,
+            '-' | '.'
+                | '_'
+                | '~'
+                | '!'
+                | '$'
+                | '&'
+                | '\''
+                | '('
+                | ')'
+                | '*'
+                | '+'
+                | ','
+                | ';'
+                | '='
+                | ':'
+                | '@'
+        )
+}
+
+#[inline]
+fn hex_value(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Errors which can occur when decoding a percent-encoded string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DecodeError {
+    /// A `%` which is not followed by two hexadecimal digits at the byte offset.
+    Malformed(usize),
+    /// The decoded bytes are not valid UTF-8.
+    InvalidUtf8,
+}
+
+/// Find the byte offset of a percent-encoded byte in a string which satisfies `f`.
+pub(crate) fn find_encoded(s: &str, f: impl Fn(u8) -> bool) -> Option<usize> {
+    let bytes = s.as_bytes();
+
+    (0..bytes.len()).find(|&i| {
+        if bytes[i] != b'%' {
+            return false;
+        }
+
+        let h = bytes.get(i + 1).and_then(|&b| hex_value(b));
+        let l = bytes.get(i + 2).and_then(|&b| hex_value(b));
+
+        match (h, l) {
+            (Some(h), Some(l)) => f(h << 4 | l),
+            _ => false,
+        }
+    })
+}
+
+/// Decode a percent-encoded string.
+pub(crate) fn decode(s: &str) -> Result<Cow<'_, str>, DecodeError> {
+    if !s.contains('%') {
+        return Ok(Cow::from(s));
+    }
+
+    let bytes = s.as_bytes();
+    let mut v = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let h = bytes.get(i + 1).and_then(|&b| hex_value(b));
+            let l = bytes.get(i + 2).and_then(|&b| hex_value(b));
+
+            match (h, l) {
+                (Some(h), Some(l)) => {
+                    v.push(h << 4 | l);
+                    i += 3;
+                },
+                _ => return Err(DecodeError::Malformed(i)),
+            }
+        } else {
+            v.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    String::from_utf8(v).map(Cow::from).map_err(|_| DecodeError::InvalidUtf8)
+}
@@ -0,0 +1,192 @@
+use alloc::{borrow::Cow, string::String};
+
+use crate::{Backslash, FileSeparator, Separator, Slash};
+
+/// Get the part of `path` after `base` without separators at its start, if `path` is `base` or is under `base`, segment by segment.
+pub(crate) fn strip_base<'a, S: Separator>(path: &'a str, base: &str) -> Option<&'a str> {
+    let base = S::delete_end_separator(base);
+
+    if base.is_empty() {
+        return Some(path);
+    }
+
+    let rest = path.strip_prefix(base)?;
+
+    if base.ends_with(S::SEPARATOR) || rest.is_empty() || rest.starts_with(S::SEPARATOR) {
+        Some(rest.trim_start_matches(S::SEPARATOR))
+    } else {
+        None
+    }
+}
+
+fn rebase<F: Separator, T: Separator>(path: &str, from: &str, to: &str) -> Option<String> {
+    let rest = strip_base::<F>(path, from)?;
+
+    let mut s = String::with_capacity(to.len() + rest.len() + 1);
+
+    s.push_str(to);
+
+    let rest = if F::SEPARATOR == T::SEPARATOR {
+        Cow::from(rest)
+    } else {
+        Cow::from(rest.replace(F::SEPARATOR, T::SEPARATOR_STR))
+    };
+
+    // the separator is only added after a non-empty new base, so that an empty new base keeps the path relative
+    if to.is_empty() {
+        s.push_str(T::delete_end_separator(&rest));
+    } else {
+        T::concat_with_separator_in_place(&mut s, &rest);
+    }
+
+    Some(s)
+}
+
+/// Replace the base `from` of a path separated by slashes with `to`. The base is matched segment by segment, and the rest is joined with the rules of `concat_with_slash`. Returns `None` if the path is not under `from`.
+///
+/// ```
+/// assert_eq!(
+///     Some(String::from("/mnt/new/x/y")),
+///     slash_formatter::rebase_slash("/mnt/old/x/y", "/mnt/old/", "/mnt/new")
+/// );
+///
+/// assert_eq!(
+///     Some(String::from("/mnt/new")),
+///     slash_formatter::rebase_slash("/mnt/old", "/mnt/old", "/mnt/new")
+/// );
+///
+/// assert_eq!(
+///     None,
+///     slash_formatter::rebase_slash("/mnt/older/x", "/mnt/old", "/mnt/new")
+/// );
+///
+/// assert_eq!(
+///     Some(String::from("x/y")),
+///     slash_formatter::rebase_slash("/a/x/y", "/a", "")
+/// );
+///
+/// assert_eq!(
+///     Some(String::from("")),
+///     slash_formatter::rebase_slash("/a", "/a", "")
+/// );
+///
+/// assert_eq!(
+///     Some(String::from("x")),
+///     slash_formatter::rebase_slash("/a/x/", "/a", "")
+/// );
+///
+/// assert_eq!(
+///     Some(String::from("/b/x")),
+///     slash_formatter::rebase_slash("/a/x/", "/a", "/b")
+/// );
+/// ```
+#[inline]
+pub fn rebase_slash<S1: ?Sized + AsRef<str>, S2: ?Sized + AsRef<str>, S3: ?Sized + AsRef<str>>(
+    path: &S1,
+    from: &S2,
+    to: &S3,
+) -> Option<String> {
+    rebase::<Slash, Slash>(path.as_ref(), from.as_ref(), to.as_ref())
+}
+
+/// Replace the base `from` of a path separated by backslashes with `to`. The base is matched segment by segment, and the rest is joined with the rules of `concat_with_backslash`. Returns `None` if the path is not under `from`.
+///
+/// ```
+/// assert_eq!(
+///     Some(String::from("D:\\data\\x")),
+///     slash_formatter::rebase_backslash(
+///         "C:\\data\\x",
+///         "C:\\data",
+///         "D:\\data"
+///     )
+/// );
+/// ```
+#[inline]
+pub fn rebase_backslash<
+    S1: ?Sized + AsRef<str>,
+    S2: ?Sized + AsRef<str>,
+    S3: ?Sized + AsRef<str>,
+>(
+    path: &S1,
+    from: &S2,
+    to: &S3,
+) -> Option<String> {
+    rebase::<Backslash, Backslash>(path.as_ref(), from.as_ref(), to.as_ref())
+}
+
+/// Replace the base `from` of a path separated by `FILE_SEPARATOR` with `to`. The base is matched segment by segment, and the rest is joined with the rules of `concat_with_file_separator`. Returns `None` if the path is not under `from`.
+///
+/// ```
+/// assert_eq!(
+///     Some(String::from(slash_formatter::concat_with_file_separator!(
+///         "new", "x"
+///     ))),
+///     slash_formatter::rebase_file_separator(
+///         slash_formatter::concat_with_file_separator!("old", "x"),
+///         "old",
+///         "new"
+///     )
+/// );
+/// ```
+#[inline]
+pub fn rebase_file_separator<
+    S1: ?Sized + AsRef<str>,
+    S2: ?Sized + AsRef<str>,
+    S3: ?Sized + AsRef<str>,
+>(
+    path: &S1,
+    from: &S2,
+    to: &S3,
+) -> Option<String> {
+    rebase::<FileSeparator, FileSeparator>(path.as_ref(), from.as_ref(), to.as_ref())
+}
+
+/// Replace the base `from` of a path separated by backslashes with `to`, which is separated by slashes. The rest of the path is converted to use slashes and joined with the rules of `concat_with_slash`. Returns `None` if the path is not under `from`.
+///
+/// ```
+/// assert_eq!(
+///     Some(String::from("s3://bucket/x/y.txt")),
+///     slash_formatter::rebase_backslash_to_slash(
+///         "C:\\data\\x\\y.txt",
+///         "C:\\data",
+///         "s3://bucket"
+///     )
+/// );
+/// ```
+#[inline]
+pub fn rebase_backslash_to_slash<
+    S1: ?Sized + AsRef<str>,
+    S2: ?Sized + AsRef<str>,
+    S3: ?Sized + AsRef<str>,
+>(
+    path: &S1,
+    from: &S2,
+    to: &S3,
+) -> Option<String> {
+    rebase::<Backslash, Slash>(path.as_ref(), from.as_ref(), to.as_ref())
+}
+
+/// Replace the base `from` of a path separated by slashes with `to`, which is separated by backslashes. The rest of the path is converted to use backslashes and joined with the rules of `concat_with_backslash`. Returns `None` if the path is not under `from`.
+///
+/// ```
+/// assert_eq!(
+///     Some(String::from("\\\\server\\share\\x\\y")),
+///     slash_formatter::rebase_slash_to_backslash(
+///         "/srv/share/x/y",
+///         "/srv/share",
+///         "\\\\server\\share"
+///     )
+/// );
+/// ```
+#[inline]
+pub fn rebase_slash_to_backslash<
+    S1: ?Sized + AsRef<str>,
+    S2: ?Sized + AsRef<str>,
+    S3: ?Sized + AsRef<str>,
+>(
+    path: &S1,
+    from: &S2,
+    to: &S3,
+) -> Option<String> {
+    rebase::<Slash, Backslash>(path.as_ref(), from.as_ref(), to.as_ref())
+}
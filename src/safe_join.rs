@@ -0,0 +1,334 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display, Formatter};
+
+use crate::{windows, Backslash, Separator, Slash};
+
+/// Errors which can occur when joining an untrusted string onto a root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JoinError {
+    /// A `..` segment at the byte offset would escape the root.
+    ParentEscape { offset: usize },
+    /// A NUL byte at the byte offset.
+    NulByte { offset: usize },
+    /// The untrusted string starts with a separator.
+    Absolute,
+    /// The untrusted string starts with a drive letter, such as `C:`.
+    DriveLetter,
+    /// The untrusted string starts with a UNC prefix, `\\`.
+    UncPrefix,
+    /// A separator of the other kind at the byte offset.
+    MixedSeparator { offset: usize },
+}
+
+impl Display for JoinError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            JoinError::ParentEscape {
+                offset,
+            } => write!(f, "the `..` segment at {} escapes the root", offset),
+            JoinError::NulByte {
+                offset,
+            } => write!(f, "a NUL byte at {}", offset),
+            JoinError::Absolute => f.write_str("the path is absolute"),
+            JoinError::DriveLetter => f.write_str("the path starts with a drive letter"),
+            JoinError::UncPrefix => f.write_str("the path starts with a UNC prefix"),
+            JoinError::MixedSeparator {
+                offset,
+            } => write!(f, "a mixed separator at {}", offset),
+        }
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for JoinError {}
+
+fn safe_join<S: Separator>(
+    root: String,
+    path: &str,
+    other_separator: char,
+    windows: bool,
+    clamp: bool,
+) -> Result<String, JoinError> {
+    if let Some(offset) = path.find('\0') {
+        return Err(JoinError::NulByte {
+            offset,
+        });
+    }
+
+    if let Some(offset) = path.find(other_separator) {
+        return Err(JoinError::MixedSeparator {
+            offset,
+        });
+    }
+
+    if windows {
+        if windows::has_unc_prefix(path) {
+            return Err(JoinError::UncPrefix);
+        }
+
+        if windows::has_drive_letter(path) {
+            return Err(JoinError::DriveLetter);
+        }
+    }
+
+    if path.starts_with(S::SEPARATOR) {
+        return Err(JoinError::Absolute);
+    }
+
+    let mut segments: Vec<&str> = Vec::new();
+    let mut offset = 0;
+
+    for raw_segment in path.split(S::SEPARATOR) {
+        // Win32 strips ending dots and spaces, so `.. ` or `...` may climb up like `..`
+        let segment = if windows && !matches!(raw_segment, "" | "." | "..") {
+            match raw_segment.trim_end_matches(|c| c == '.' || c == ' ') {
+                "" => "..",
+                trimmed => trimmed,
+            }
+        } else {
+            raw_segment
+        };
+
+        match segment {
+            "" | "." => (),
+            ".." => {
+                if segments.pop().is_none() && !clamp {
+                    return Err(JoinError::ParentEscape {
+                        offset,
+                    });
+                }
+            },
+            _ => segments.push(segment),
+        }
+
+        offset += raw_segment.len() + S::SEPARATOR.len_utf8();
+    }
+
+    let mut s = root;
+
+    if s.is_empty() {
+        // a relative root must stay relative, so no starting separator is added
+        for (i, segment) in segments.into_iter().enumerate() {
+            if i > 0 {
+                s.push(S::SEPARATOR);
+            }
+
+            s.push_str(segment);
+        }
+    } else if segments.is_empty() {
+        S::concat_with_separator_in_place(&mut s, "");
+    } else {
+        for segment in segments {
+            S::concat_with_separator_in_place(&mut s, segment);
+        }
+    }
+
+    Ok(s)
+}
+
+/// Concatenate a trusted root and an untrusted string with slashes, lexically resolving `.` and `..` segments. Fails if the untrusted string could escape the root.
+///
+/// ```
+/// use slash_formatter::JoinError;
+///
+/// assert_eq!(
+///     Ok(String::from("/srv/www/css/site.css")),
+///     slash_formatter::safe_join_slash("/srv/www", "js/../css/./site.css")
+/// );
+///
+/// assert_eq!(
+///     Err(JoinError::ParentEscape {
+///         offset: 5
+///     }),
+///     slash_formatter::safe_join_slash("/srv/www", "a/../../etc/passwd")
+/// );
+///
+/// assert_eq!(
+///     Err(JoinError::Absolute),
+///     slash_formatter::safe_join_slash("/srv/www", "/etc/passwd")
+/// );
+///
+/// assert_eq!(
+///     Ok(String::from("etc/passwd")),
+///     slash_formatter::safe_join_slash("", "etc/passwd")
+/// );
+///
+/// assert_eq!(
+///     Err(JoinError::ParentEscape {
+///         offset: 0
+///     }),
+///     slash_formatter::safe_join_slash("", "../etc/passwd")
+/// );
+/// ```
+#[inline]
+pub fn safe_join_slash<S1: Into<String>, S2: AsRef<str>>(
+    root: S1,
+    path: S2,
+) -> Result<String, JoinError> {
+    safe_join::<Slash>(root.into(), path.as_ref(), '\\', false, false)
+}
+
+/// Concatenate a trusted root and an untrusted string with slashes, lexically resolving `.` and `..` segments. `..` segments which would escape the root are ignored.
+///
+/// ```
+/// assert_eq!(
+///     Ok(String::from("/srv/www/etc/passwd")),
+///     slash_formatter::safe_join_slash_clamped(
+///         "/srv/www",
+///         "../../etc/passwd"
+///     )
+/// );
+///
+/// assert_eq!(
+///     Ok(String::from("etc/passwd")),
+///     slash_formatter::safe_join_slash_clamped("", "../etc/passwd")
+/// );
+/// ```
+#[inline]
+pub fn safe_join_slash_clamped<S1: Into<String>, S2: AsRef<str>>(
+    root: S1,
+    path: S2,
+) -> Result<String, JoinError> {
+    safe_join::<Slash>(root.into(), path.as_ref(), '\\', false, true)
+}
+
+/// Concatenate a trusted root and an untrusted string with backslashes, lexically resolving `.` and `..` segments. Ending dots and spaces of segments are stripped like Win32 does, and a segment made of only dots and spaces is treated as `..`. Fails if the untrusted string could escape the root.
+///
+/// ```
+/// use slash_formatter::JoinError;
+///
+/// assert_eq!(
+///     Ok(String::from("C:\\www\\site.css")),
+///     slash_formatter::safe_join_backslash("C:\\www", "css\\..\\site.css")
+/// );
+///
+/// assert_eq!(
+///     Err(JoinError::DriveLetter),
+///     slash_formatter::safe_join_backslash("C:\\www", "D:secret")
+/// );
+///
+/// assert_eq!(
+///     Err(JoinError::UncPrefix),
+///     slash_formatter::safe_join_backslash("C:\\www", "\\\\server\\share")
+/// );
+///
+/// assert_eq!(
+///     Err(JoinError::MixedSeparator {
+///         offset: 5
+///     }),
+///     slash_formatter::safe_join_backslash("C:\\www", "..\\../x")
+/// );
+///
+/// assert_eq!(
+///     Ok(String::from("windows\\system32")),
+///     slash_formatter::safe_join_backslash("", "windows\\system32")
+/// );
+///
+/// assert_eq!(
+///     Err(JoinError::ParentEscape {
+///         offset: 0
+///     }),
+///     slash_formatter::safe_join_backslash("C:\\www", ".. \\x")
+/// );
+///
+/// assert_eq!(
+///     Err(JoinError::ParentEscape {
+///         offset: 0
+///     }),
+///     slash_formatter::safe_join_backslash("C:\\www", "...\\x")
+/// );
+///
+/// assert_eq!(
+///     Ok(String::from("C:\\www\\a\\x")),
+///     slash_formatter::safe_join_backslash("C:\\www", "a. \\x")
+/// );
+/// ```
+#[inline]
+pub fn safe_join_backslash<S1: Into<String>, S2: AsRef<str>>(
+    root: S1,
+    path: S2,
+) -> Result<String, JoinError> {
+    safe_join::<Backslash>(root.into(), path.as_ref(), '/', true, false)
+}
+
+/// Concatenate a trusted root and an untrusted string with backslashes, lexically resolving `.` and `..` segments. Ending dots and spaces of segments are stripped like Win32 does, and a segment made of only dots and spaces is treated as `..`. `..` segments which would escape the root are ignored.
+///
+/// ```
+/// assert_eq!(
+///     Ok(String::from("C:\\www\\x")),
+///     slash_formatter::safe_join_backslash_clamped("C:\\www", "..\\..\\x")
+/// );
+///
+/// assert_eq!(
+///     Ok(String::from("x")),
+///     slash_formatter::safe_join_backslash_clamped("", "..\\x")
+/// );
+/// ```
+#[inline]
+pub fn safe_join_backslash_clamped<S1: Into<String>, S2: AsRef<str>>(
+    root: S1,
+    path: S2,
+) -> Result<String, JoinError> {
+    safe_join::<Backslash>(root.into(), path.as_ref(), '/', true, true)
+}
+
+/// Concatenate a trusted root and an untrusted string with `FILE_SEPARATOR`, lexically resolving `.` and `..` segments. Fails if the untrusted string could escape the root.
+///
+/// ```
+/// assert_eq!(
+///     Ok(slash_formatter::file_separator!("root", "b")),
+///     slash_formatter::safe_join_file_separator(
+///         "root",
+///         slash_formatter::concat_with_file_separator!("a", "..", "b")
+///     )
+/// );
+///
+/// assert!(slash_formatter::safe_join_file_separator(
+///     "root",
+///     slash_formatter::concat_with_file_separator!("..", "b")
+/// )
+/// .is_err());
+/// ```
+#[inline]
+pub fn safe_join_file_separator<S1: Into<String>, S2: AsRef<str>>(
+    root: S1,
+    path: S2,
+) -> Result<String, JoinError> {
+    #[cfg(unix)]
+    {
+        safe_join_slash(root, path)
+    }
+
+    #[cfg(windows)]
+    {
+        safe_join_backslash(root, path)
+    }
+}
+
+/// Concatenate a trusted root and an untrusted string with `FILE_SEPARATOR`, lexically resolving `.` and `..` segments. `..` segments which would escape the root are ignored.
+///
+/// ```
+/// assert_eq!(
+///     Ok(slash_formatter::file_separator!("root", "b")),
+///     slash_formatter::safe_join_file_separator_clamped(
+///         "root",
+///         slash_formatter::concat_with_file_separator!("..", "b")
+///     )
+/// );
+/// ```
+#[inline]
+pub fn safe_join_file_separator_clamped<S1: Into<String>, S2: AsRef<str>>(
+    root: S1,
+    path: S2,
+) -> Result<String, JoinError> {
+    #[cfg(unix)]
+    {
+        safe_join_slash_clamped(root, path)
+    }
+
+    #[cfg(windows)]
+    {
+        safe_join_backslash_clamped(root, path)
+    }
+}
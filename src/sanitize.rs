@@ -0,0 +1,315 @@
+use alloc::{borrow::Cow, string::String};
+use core::fmt::{self, Display, Formatter};
+
+use crate::{percent, windows, Backslash, FileSeparatorBuild, Separator};
+
+/// The way to deal with characters which cannot be used in a file name on Windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SanitizeStrategy {
+    /// Replace every offending character with the given character, which must be legal in a file name and must not be a dot or a space. Reserved device names get the character appended to their stems. Use `SanitizeStrategy::replace` to check the character.
+    Replace(char),
+    /// Remove offending characters. Reserved device names get `_` appended to their stems.
+    Remove,
+    /// Percent-encode offending characters and `%` itself, such as `%3F` for `?`. Reserved device names get the last characters of their stems encoded.
+    Escape,
+}
+
+/// An error indicating a replacement character which cannot be used in a file name on Windows, or which cannot end one, such as `?`, `\\` or `.`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidReplacementError {
+    /// The replacement character.
+    pub replacement: char,
+}
+
+impl Display for InvalidReplacementError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} cannot be used as a replacement", self.replacement)
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for InvalidReplacementError {}
+
+#[inline]
+fn is_valid_replacement(c: char) -> bool {
+    !(windows::is_illegal_char(c) || matches!(c, '/' | '\\' | '.' | ' '))
+}
+
+impl SanitizeStrategy {
+    /// Create a `Replace` strategy, checking that the replacement character can be used in a file name on Windows.
+    ///
+    /// ```
+    /// use slash_formatter::{InvalidReplacementError, SanitizeStrategy};
+    ///
+    /// assert_eq!(
+    ///     Ok(SanitizeStrategy::Replace('_')),
+    ///     SanitizeStrategy::replace('_')
+    /// );
+    /// assert_eq!(
+    ///     Err(InvalidReplacementError {
+    ///         replacement: '?'
+    ///     }),
+    ///     SanitizeStrategy::replace('?')
+    /// );
+    /// ```
+    #[inline]
+    pub fn replace(replacement: char) -> Result<Self, InvalidReplacementError> {
+        if is_valid_replacement(replacement) {
+            Ok(SanitizeStrategy::Replace(replacement))
+        } else {
+            Err(InvalidReplacementError {
+                replacement,
+            })
+        }
+    }
+
+    #[inline]
+    fn assert_valid(self) {
+        if let SanitizeStrategy::Replace(r) = self {
+            assert!(is_valid_replacement(r), "{:?} cannot be used as a replacement", r);
+        }
+    }
+
+    #[inline]
+    fn push(self, s: &mut String, c: char) {
+        match self {
+            SanitizeStrategy::Replace(r) => s.push(r),
+            SanitizeStrategy::Remove => (),
+            SanitizeStrategy::Escape => percent::push_encoded_char(s, c),
+        }
+    }
+}
+
+#[inline]
+fn is_offending_char(c: char, strategy: SanitizeStrategy) -> bool {
+    windows::is_illegal_char(c)
+        || c == '/'
+        || c == '\\'
+        || (c == '%' && strategy == SanitizeStrategy::Escape)
+}
+
+#[inline]
+fn needs_sanitizing(segment: &str, strategy: SanitizeStrategy) -> bool {
+    segment.is_empty()
+        || segment.ends_with('.')
+        || segment.ends_with(' ')
+        || segment.chars().any(|c| is_offending_char(c, strategy))
+        || windows::is_reserved_name(segment)
+}
+
+fn sanitize_segment(segment: &str, strategy: SanitizeStrategy) -> Cow<'_, str> {
+    strategy.assert_valid();
+
+    if !needs_sanitizing(segment, strategy) {
+        return Cow::from(segment);
+    }
+
+    let body = segment.trim_end_matches(|c| c == '.' || c == ' ');
+    let tail = &segment[body.len()..];
+
+    let mut s = String::with_capacity(segment.len());
+
+    for c in body.chars() {
+        if is_offending_char(c, strategy) {
+            strategy.push(&mut s, c);
+        } else {
+            s.push(c);
+        }
+    }
+
+    if windows::is_reserved_name(&s) {
+        let stem_length = s.find('.').unwrap_or(s.len());
+
+        match strategy {
+            SanitizeStrategy::Replace(r) => s.insert(stem_length, r),
+            SanitizeStrategy::Remove => s.insert(stem_length, '_'),
+            SanitizeStrategy::Escape => {
+                // device names are ASCII, so the last character of the stem is one byte long
+                let c = s.remove(stem_length - 1);
+
+                let mut encoded = String::new();
+
+                percent::push_encoded_char(&mut encoded, c);
+
+                s.insert_str(stem_length - 1, &encoded);
+            },
+        }
+    }
+
+    for c in tail.chars() {
+        strategy.push(&mut s, c);
+    }
+
+    if s.is_empty() {
+        s.push('_');
+    }
+
+    Cow::from(s)
+}
+
+/// Whether a segment of a path needs sanitizing. `.` and `..` segments are kept, because they are references rather than file names.
+#[inline]
+fn path_segment_needs_sanitizing(segment: &str, strategy: SanitizeStrategy) -> bool {
+    !matches!(segment, "" | "." | "..") && needs_sanitizing(segment, strategy)
+}
+
+fn sanitize_path<S: Separator>(s: &str, strategy: SanitizeStrategy) -> Cow<'_, str> {
+    strategy.assert_valid();
+
+    let prefix_length = if S::SEPARATOR == '\\' {
+        if s.starts_with("\\\\?\\") || s.starts_with("\\\\.\\") {
+            4
+        } else if windows::has_unc_prefix(s) || windows::has_drive_letter(s) {
+            2
+        } else {
+            0
+        }
+    } else {
+        0
+    };
+
+    let (prefix, body) = s.split_at(prefix_length);
+
+    let needs =
+        body.split(S::SEPARATOR).any(|segment| path_segment_needs_sanitizing(segment, strategy));
+
+    if !needs {
+        return Cow::from(s);
+    }
+
+    let mut output = String::with_capacity(s.len());
+
+    output.push_str(prefix);
+
+    for (index, segment) in body.split(S::SEPARATOR).enumerate() {
+        if index > 0 {
+            output.push(S::SEPARATOR);
+        }
+
+        if path_segment_needs_sanitizing(segment, strategy) {
+            output.push_str(&sanitize_segment(segment, strategy));
+        } else {
+            output.push_str(segment);
+        }
+    }
+
+    Cow::from(output)
+}
+
+/// Sanitize a segment so that it can be used as a file name on Windows. Slashes, backslashes, the characters `<>:"|?*`, control characters, reserved device names such as `CON` and `aux.txt`, and ending dots and spaces are dealt with by the strategy, so `.` and `..` are not kept either. An empty result becomes `_`.
+///
+/// # Panics
+///
+/// Panics if the strategy is `Replace` with an invalid replacement character.
+///
+/// ```
+/// use slash_formatter::SanitizeStrategy;
+///
+/// assert_eq!(
+///     "a_b_",
+///     slash_formatter::sanitize_backslash_segment(
+///         "a?b.",
+///         SanitizeStrategy::Replace('_')
+///     )
+/// );
+/// assert_eq!(
+///     "aux_.txt",
+///     slash_formatter::sanitize_backslash_segment(
+///         "aux.txt",
+///         SanitizeStrategy::Remove
+///     )
+/// );
+/// assert_eq!(
+///     "__",
+///     slash_formatter::sanitize_backslash_segment(
+///         "..",
+///         SanitizeStrategy::Replace('_')
+///     )
+/// );
+/// assert_eq!(
+///     "CO%4E",
+///     slash_formatter::sanitize_backslash_segment(
+///         "CON",
+///         SanitizeStrategy::Escape
+///     )
+/// );
+/// assert_eq!(
+///     "out\\CON_",
+///     slash_formatter::concat_with_backslash(
+///         "out",
+///         slash_formatter::sanitize_backslash_segment(
+///             "CON",
+///             SanitizeStrategy::Replace('_')
+///         )
+///     )
+/// );
+/// ```
+#[inline]
+pub fn sanitize_backslash_segment<S: ?Sized + AsRef<str>>(
+    s: &S,
+    strategy: SanitizeStrategy,
+) -> Cow<'_, str> {
+    sanitize_segment(s.as_ref(), strategy)
+}
+
+/// Sanitize every segment of a string separated by backslashes with `sanitize_backslash_segment`. A drive letter, a UNC prefix, empty segments and `.` and `..` segments are kept.
+///
+/// # Panics
+///
+/// Panics if the strategy is `Replace` with an invalid replacement character.
+///
+/// ```
+/// use slash_formatter::SanitizeStrategy;
+///
+/// assert_eq!(
+///     "C:\\nul_\\a_b",
+///     slash_formatter::sanitize_backslash(
+///         "C:\\nul\\a*b",
+///         SanitizeStrategy::Replace('_')
+///     )
+/// );
+/// assert_eq!(
+///     "a\\..\\b_",
+///     slash_formatter::sanitize_backslash(
+///         "a\\..\\b.",
+///         SanitizeStrategy::Replace('_')
+///     )
+/// );
+/// ```
+#[inline]
+pub fn sanitize_backslash<S: ?Sized + AsRef<str>>(
+    s: &S,
+    strategy: SanitizeStrategy,
+) -> Cow<'_, str> {
+    sanitize_path::<Backslash>(s.as_ref(), strategy)
+}
+
+/// Sanitize every segment of a string separated by ``FILE_SEPARATOR_ON_WORKSTATION`` with `sanitize_backslash_segment`, so that the files can be extracted on Windows later. Empty segments and `.` and `..` segments are kept.
+///
+/// # Panics
+///
+/// Panics if the strategy is `Replace` with an invalid replacement character.
+///
+/// ```
+/// use slash_formatter::SanitizeStrategy;
+///
+/// assert_eq!(
+///     slash_formatter::concat_with_file_separator_build!(
+///         "out", "CON_", "a_b"
+///     ),
+///     slash_formatter::sanitize_file_separator_build(
+///         slash_formatter::concat_with_file_separator_build!(
+///             "out", "CON", "a|b"
+///         ),
+///         SanitizeStrategy::Replace('_')
+///     )
+/// );
+/// ```
+#[inline]
+pub fn sanitize_file_separator_build<S: ?Sized + AsRef<str>>(
+    s: &S,
+    strategy: SanitizeStrategy,
+) -> Cow<'_, str> {
+    sanitize_path::<FileSeparatorBuild>(s.as_ref(), strategy)
+}
@@ -0,0 +1,180 @@
+use alloc::string::String;
+use core::{fmt::Debug, hash::Hash, iter::FusedIterator, marker::PhantomData};
+
+/// A kind of separator which delimits the segments of a string, such as a slash or a backslash.
+pub trait Separator: Debug + Copy + Eq + Ord + Hash + Default {
+    /// The separator character.
+    const SEPARATOR: char;
+
+    /// The separator as a string slice.
+    const SEPARATOR_STR: &'static str;
+
+    /// Delete an ending separator in a string except for just the separator.
+    fn delete_end_separator(s: &str) -> &str;
+
+    /// Delete a starting separator in a string except for just the separator.
+    fn delete_start_separator(s: &str) -> &str;
+
+    /// Concatenate two strings with the separator.
+    fn concat_with_separator_in_place(s1: &mut String, s2: &str);
+}
+
+/// The slash, `'/'`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Slash;
+
+impl Separator for Slash {
+    const SEPARATOR: char = '/';
+    const SEPARATOR_STR: &'static str = "/";
+
+    #[inline]
+    fn delete_end_separator(s: &str) -> &str {
+        crate::delete_end_slash(s)
+    }
+
+    #[inline]
+    fn delete_start_separator(s: &str) -> &str {
+        crate::delete_start_slash(s)
+    }
+
+    #[inline]
+    fn concat_with_separator_in_place(s1: &mut String, s2: &str) {
+        crate::concat_with_slash_in_place(s1, s2)
+    }
+}
+
+/// The backslash, `'\\'`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Backslash;
+
+impl Separator for Backslash {
+    const SEPARATOR: char = '\\';
+    const SEPARATOR_STR: &'static str = "\\";
+
+    #[inline]
+    fn delete_end_separator(s: &str) -> &str {
+        crate::delete_end_backslash(s)
+    }
+
+    #[inline]
+    fn delete_start_separator(s: &str) -> &str {
+        crate::delete_start_backslash(s)
+    }
+
+    #[inline]
+    fn concat_with_separator_in_place(s1: &mut String, s2: &str) {
+        crate::concat_with_backslash_in_place(s1, s2)
+    }
+}
+
+/// The `FILE_SEPARATOR` of the target OS.
+#[cfg(unix)]
+pub type FileSeparator = Slash;
+
+/// The `FILE_SEPARATOR` of the target OS.
+#[cfg(windows)]
+pub type FileSeparator = Backslash;
+
+/// The ``FILE_SEPARATOR_ON_WORKSTATION``.
+#[cfg(from_unix)]
+pub type FileSeparatorBuild = Slash;
+
+/// The ``FILE_SEPARATOR_ON_WORKSTATION``.
+#[cfg(from_windows)]
+pub type FileSeparatorBuild = Backslash;
+
+/// An iterator over the segments of a string separated by `S`.
+///
+/// A starting separator and an ending separator are not treated as delimiters of empty segments, following the rules of `delete_start_*` and `delete_end_*`. Empty segments between two consecutive separators are still yielded.
+#[derive(Debug, Clone)]
+pub struct Segments<'a, S: Separator> {
+    remaining:  Option<&'a str>,
+    _separator: PhantomData<S>,
+}
+
+impl<'a, S: Separator> Segments<'a, S> {
+    /// Create an iterator over the segments of `s`.
+    ///
+    /// ```
+    /// use slash_formatter::{Segments, Slash};
+    ///
+    /// let segments: Vec<&str> = Segments::<Slash>::new("/path/to/").collect();
+    ///
+    /// assert_eq!(vec!["path", "to"], segments);
+    /// ```
+    #[inline]
+    pub fn new(s: &'a str) -> Self {
+        let s = S::delete_end_separator(s);
+
+        let remaining = if s.is_empty() || s == S::SEPARATOR_STR {
+            None
+        } else {
+            Some(S::delete_start_separator(s))
+        };
+
+        Segments {
+            remaining,
+            _separator: PhantomData,
+        }
+    }
+
+    /// Get the part of the string which has not been iterated yet.
+    ///
+    /// ```
+    /// use slash_formatter::{Segments, Slash};
+    ///
+    /// let mut segments = Segments::<Slash>::new("/path/to/file");
+    ///
+    /// segments.next();
+    ///
+    /// assert_eq!("to/file", segments.as_str());
+    /// ```
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        self.remaining.unwrap_or("")
+    }
+}
+
+impl<'a, S: Separator> Iterator for Segments<'a, S> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining?;
+
+        match remaining.find(S::SEPARATOR) {
+            Some(index) => {
+                self.remaining = Some(&remaining[index + S::SEPARATOR.len_utf8()..]);
+
+                Some(&remaining[..index])
+            },
+            None => {
+                self.remaining = None;
+
+                Some(remaining)
+            },
+        }
+    }
+}
+
+impl<'a, S: Separator> DoubleEndedIterator for Segments<'a, S> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining?;
+
+        match remaining.rfind(S::SEPARATOR) {
+            Some(index) => {
+                self.remaining = Some(&remaining[..index]);
+
+                Some(&remaining[index + S::SEPARATOR.len_utf8()..])
+            },
+            None => {
+                self.remaining = None;
+
+                Some(remaining)
+            },
+        }
+    }
+}
+
+impl<'a, S: Separator> FusedIterator for Segments<'a, S> {}
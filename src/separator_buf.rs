@@ -0,0 +1,222 @@
+use alloc::string::String;
+
+/// A growable string buffer which the `_in_place` functions can modify.
+///
+/// Implementations are provided for `String` and, with the features of the same names, for `compact_str::CompactString`, `smartstring::SmartString`, `arrayvec::ArrayString` and `heapless::String`. Fixed-capacity buffers panic if they overflow, just like `arrayvec::ArrayString::push`.
+///
+/// ```
+/// use slash_formatter::SeparatorBuf;
+///
+/// #[derive(Default)]
+/// struct Key(String);
+///
+/// impl AsRef<str> for Key {
+///     fn as_ref(&self) -> &str {
+///         &self.0
+///     }
+/// }
+///
+/// impl SeparatorBuf for Key {
+///     fn push(&mut self, c: char) {
+///         self.0.push(c)
+///     }
+///
+///     fn push_str(&mut self, s: &str) {
+///         self.0.push_str(s)
+///     }
+///
+///     fn insert(&mut self, index: usize, c: char) {
+///         self.0.insert(index, c)
+///     }
+///
+///     fn remove(&mut self, index: usize) -> char {
+///         self.0.remove(index)
+///     }
+///
+///     fn truncate(&mut self, new_len: usize) {
+///         self.0.truncate(new_len)
+///     }
+/// }
+///
+/// let mut key = Key::default();
+///
+/// slash_formatter::slash_in_place!(&mut key, "path/", "/to/");
+///
+/// assert_eq!("/path/to", key.as_ref());
+///
+/// #[cfg(feature = "arrayvec")]
+/// {
+///     let mut key = arrayvec::ArrayString::<16>::new();
+///
+///     slash_formatter::slash_in_place!(&mut key, "path/", "/to/");
+///
+///     assert_eq!("/path/to", key.as_str());
+/// }
+/// ```
+pub trait SeparatorBuf: AsRef<str> {
+    /// Append a character to the end of the buffer.
+    fn push(&mut self, c: char);
+
+    /// Append a string slice to the end of the buffer.
+    fn push_str(&mut self, s: &str);
+
+    /// Insert a character at a byte position of the buffer.
+    fn insert(&mut self, index: usize, c: char);
+
+    /// Remove a character at a byte position of the buffer and return it.
+    fn remove(&mut self, index: usize) -> char;
+
+    /// Shorten the buffer to the byte length.
+    fn truncate(&mut self, new_len: usize);
+}
+
+impl SeparatorBuf for String {
+    #[inline]
+    fn push(&mut self, c: char) {
+        String::push(self, c)
+    }
+
+    #[inline]
+    fn push_str(&mut self, s: &str) {
+        String::push_str(self, s)
+    }
+
+    #[inline]
+    fn insert(&mut self, index: usize, c: char) {
+        String::insert(self, index, c)
+    }
+
+    #[inline]
+    fn remove(&mut self, index: usize) -> char {
+        String::remove(self, index)
+    }
+
+    #[inline]
+    fn truncate(&mut self, new_len: usize) {
+        String::truncate(self, new_len)
+    }
+}
+
+#[cfg(feature = "compact_str")]
+impl SeparatorBuf for compact_str::CompactString {
+    #[inline]
+    fn push(&mut self, c: char) {
+        compact_str::CompactString::push(self, c)
+    }
+
+    #[inline]
+    fn push_str(&mut self, s: &str) {
+        compact_str::CompactString::push_str(self, s)
+    }
+
+    #[inline]
+    fn insert(&mut self, index: usize, c: char) {
+        compact_str::CompactString::insert(self, index, c)
+    }
+
+    #[inline]
+    fn remove(&mut self, index: usize) -> char {
+        compact_str::CompactString::remove(self, index)
+    }
+
+    #[inline]
+    fn truncate(&mut self, new_len: usize) {
+        compact_str::CompactString::truncate(self, new_len)
+    }
+}
+
+#[cfg(feature = "smartstring")]
+impl<M: smartstring::SmartStringMode> SeparatorBuf for smartstring::SmartString<M> {
+    #[inline]
+    fn push(&mut self, c: char) {
+        smartstring::SmartString::push(self, c)
+    }
+
+    #[inline]
+    fn push_str(&mut self, s: &str) {
+        smartstring::SmartString::push_str(self, s)
+    }
+
+    #[inline]
+    fn insert(&mut self, index: usize, c: char) {
+        smartstring::SmartString::insert(self, index, c)
+    }
+
+    #[inline]
+    fn remove(&mut self, index: usize) -> char {
+        smartstring::SmartString::remove(self, index)
+    }
+
+    #[inline]
+    fn truncate(&mut self, new_len: usize) {
+        smartstring::SmartString::truncate(self, new_len)
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<const CAP: usize> SeparatorBuf for arrayvec::ArrayString<CAP> {
+    #[inline]
+    fn push(&mut self, c: char) {
+        arrayvec::ArrayString::push(self, c)
+    }
+
+    #[inline]
+    fn push_str(&mut self, s: &str) {
+        arrayvec::ArrayString::push_str(self, s)
+    }
+
+    #[inline]
+    fn insert(&mut self, index: usize, c: char) {
+        let mut s = arrayvec::ArrayString::new();
+
+        s.push_str(&self[..index]);
+        s.push(c);
+        s.push_str(&self[index..]);
+
+        *self = s;
+    }
+
+    #[inline]
+    fn remove(&mut self, index: usize) -> char {
+        arrayvec::ArrayString::remove(self, index)
+    }
+
+    #[inline]
+    fn truncate(&mut self, new_len: usize) {
+        arrayvec::ArrayString::truncate(self, new_len)
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> SeparatorBuf for heapless::String<N> {
+    #[inline]
+    fn push(&mut self, c: char) {
+        heapless::String::push(self, c).expect("the buffer is full")
+    }
+
+    #[inline]
+    fn push_str(&mut self, s: &str) {
+        heapless::String::push_str(self, s).expect("the buffer is full")
+    }
+
+    #[inline]
+    fn insert(&mut self, index: usize, c: char) {
+        let mut s = heapless::String::new();
+
+        SeparatorBuf::push_str(&mut s, &self[..index]);
+        SeparatorBuf::push(&mut s, c);
+        SeparatorBuf::push_str(&mut s, &self[index..]);
+
+        *self = s;
+    }
+
+    #[inline]
+    fn remove(&mut self, index: usize) -> char {
+        heapless::String::remove(self, index)
+    }
+
+    #[inline]
+    fn truncate(&mut self, new_len: usize) {
+        heapless::String::truncate(self, new_len)
+    }
+}
@@ -0,0 +1,226 @@
+/*!
+Adapters for `#[serde(with = "...")]` which normalize separator strings when serializing and deserializing, such as `#[serde(with = "slash_formatter::serde::trim_end_slash")]` on a `String` field.
+
+```
+use std::fmt::{self, Display, Formatter};
+
+use serde::de::{
+    value::{Error, StrDeserializer},
+    IntoDeserializer,
+};
+
+let deserializer: StrDeserializer<'_, Error> = "https://example.com/".into_deserializer();
+
+assert_eq!(
+    "https://example.com",
+    slash_formatter::serde::trim_end_slash::deserialize(deserializer).unwrap()
+);
+
+// `Formatter` is a `Serializer` which writes strings as they are
+struct Api;
+
+impl Display for Api {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        slash_formatter::serde::ensure_start_slash::serialize("api", f)
+    }
+}
+
+assert_eq!("/api", Api.to_string());
+```
+*/
+
+use alloc::{borrow::Cow, string::String};
+
+#[inline]
+fn convert<'a>(s: &'a str, from: char, to: &str) -> Cow<'a, str> {
+    if s.contains(from) {
+        Cow::from(s.replace(from, to))
+    } else {
+        Cow::from(s)
+    }
+}
+
+#[inline]
+fn convert_in_place(s: &mut String, from: char, to: &str) {
+    if s.contains(from) {
+        *s = s.replace(from, to);
+    }
+}
+
+#[inline]
+fn convert_to_slash(s: &str) -> Cow<'_, str> {
+    convert(s, '\\', "/")
+}
+
+#[inline]
+fn convert_to_slash_in_place(s: &mut String) {
+    convert_in_place(s, '\\', "/")
+}
+
+#[inline]
+fn convert_to_backslash(s: &str) -> Cow<'_, str> {
+    convert(s, '/', "\\")
+}
+
+#[inline]
+fn convert_to_backslash_in_place(s: &mut String) {
+    convert_in_place(s, '/', "\\")
+}
+
+#[cfg(windows)]
+use convert_to_backslash as convert_to_file_separator;
+#[cfg(windows)]
+use convert_to_backslash_in_place as convert_to_file_separator_in_place;
+#[cfg(unix)]
+use convert_to_slash as convert_to_file_separator;
+#[cfg(unix)]
+use convert_to_slash_in_place as convert_to_file_separator_in_place;
+
+macro_rules! adapter {
+    ($(#[$attr:meta])* $name:ident, $f:path, $f_in_place:path) => {
+        $(#[$attr])*
+        pub mod $name {
+            use alloc::string::String;
+
+            use ::serde::{Deserialize, Deserializer, Serializer};
+
+            /// Serialize a string after normalizing it.
+            #[inline]
+            pub fn serialize<T: ?Sized + AsRef<str>, S: Serializer>(
+                s: &T,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&$f(s.as_ref()))
+            }
+
+            /// Deserialize a string and normalize it.
+            #[inline]
+            pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+                let mut s = String::deserialize(deserializer)?;
+
+                $f_in_place(&mut s);
+
+                Ok(s)
+            }
+        }
+    };
+}
+
+adapter!(
+    /// Apply `delete_end_slash` to a string field.
+    trim_end_slash,
+    crate::delete_end_slash,
+    crate::delete_end_slash_in_place
+);
+
+adapter!(
+    /// Apply `delete_start_slash` to a string field.
+    trim_start_slash,
+    crate::delete_start_slash,
+    crate::delete_start_slash_in_place
+);
+
+adapter!(
+    /// Apply `add_start_slash` to a string field.
+    ensure_start_slash,
+    crate::add_start_slash,
+    crate::add_start_slash_in_place
+);
+
+adapter!(
+    /// Apply `add_end_slash` to a string field.
+    ensure_end_slash,
+    crate::add_end_slash,
+    crate::add_end_slash_in_place
+);
+
+adapter!(
+    /// Apply `delete_end_backslash` to a string field.
+    trim_end_backslash,
+    crate::delete_end_backslash,
+    crate::delete_end_backslash_in_place
+);
+
+adapter!(
+    /// Apply `delete_start_backslash` to a string field.
+    trim_start_backslash,
+    crate::delete_start_backslash,
+    crate::delete_start_backslash_in_place
+);
+
+adapter!(
+    /// Apply `add_start_backslash` to a string field.
+    ensure_start_backslash,
+    crate::add_start_backslash,
+    crate::add_start_backslash_in_place
+);
+
+adapter!(
+    /// Apply `add_end_backslash` to a string field.
+    ensure_end_backslash,
+    crate::add_end_backslash,
+    crate::add_end_backslash_in_place
+);
+
+adapter!(
+    /// Apply `delete_end_file_separator` to a string field.
+    trim_end_file_separator,
+    crate::delete_end_file_separator,
+    crate::delete_end_file_separator_in_place
+);
+
+adapter!(
+    /// Apply `delete_start_file_separator` to a string field.
+    trim_start_file_separator,
+    crate::delete_start_file_separator,
+    crate::delete_start_file_separator_in_place
+);
+
+adapter!(
+    /// Apply `add_start_file_separator` to a string field.
+    ensure_start_file_separator,
+    crate::add_start_file_separator,
+    crate::add_start_file_separator_in_place
+);
+
+adapter!(
+    /// Apply `add_end_file_separator` to a string field.
+    ensure_end_file_separator,
+    crate::add_end_file_separator,
+    crate::add_end_file_separator_in_place
+);
+
+adapter!(
+    /// Replace backslashes in a string field with slashes.
+    to_slash,
+    super::convert_to_slash,
+    super::convert_to_slash_in_place
+);
+
+adapter!(
+    /// Replace slashes in a string field with backslashes.
+    to_backslash,
+    super::convert_to_backslash,
+    super::convert_to_backslash_in_place
+);
+
+adapter!(
+    /// Replace slashes and backslashes in a string field with `FILE_SEPARATOR`.
+    ///
+    /// ```
+    /// use serde::de::{
+    ///     value::{Error, StrDeserializer},
+    ///     IntoDeserializer,
+    /// };
+    ///
+    /// let deserializer: StrDeserializer<'_, Error> = "a/b\\c".into_deserializer();
+    ///
+    /// assert_eq!(
+    ///     slash_formatter::concat_with_file_separator!("a", "b", "c"),
+    ///     slash_formatter::serde::to_file_separator::deserialize(deserializer).unwrap()
+    /// );
+    /// ```
+    to_file_separator,
+    super::convert_to_file_separator,
+    super::convert_to_file_separator_in_place
+);
@@ -1,5 +1,7 @@
 use alloc::{borrow::Cow, string::String};
 
+use crate::{Segments, SeparatorBuf, Slash};
+
 /// Delete an ending slash in a string except for '/'.
 ///
 /// ```
@@ -28,13 +30,11 @@ pub fn delete_end_slash<S: ?Sized + AsRef<str>>(s: &S) -> &str {
 /// assert_eq!("path", s);
 /// ```
 #[inline]
-pub fn delete_end_slash_in_place(s: &mut String) {
-    let length = s.len();
+pub fn delete_end_slash_in_place<B: SeparatorBuf>(s: &mut B) {
+    let length = s.as_ref().len();
 
-    if length > 1 && s.ends_with('/') {
-        unsafe {
-            s.as_mut_vec().set_len(length - 1);
-        }
+    if length > 1 && s.as_ref().ends_with('/') {
+        s.truncate(length - 1);
     }
 }
 
@@ -66,10 +66,10 @@ pub fn delete_start_slash<S: ?Sized + AsRef<str>>(s: &S) -> &str {
 /// assert_eq!("path", s);
 /// ```
 #[inline]
-pub fn delete_start_slash_in_place(s: &mut String) {
-    let length = s.len();
+pub fn delete_start_slash_in_place<B: SeparatorBuf>(s: &mut B) {
+    let length = s.as_ref().len();
 
-    if length > 1 && s.starts_with('/') {
+    if length > 1 && s.as_ref().starts_with('/') {
         s.remove(0);
     }
 }
@@ -80,6 +80,7 @@ pub fn delete_start_slash_in_place(s: &mut String) {
 /// assert_eq!("/path", slash_formatter::add_start_slash("path"));
 /// ```
 #[inline]
+#[allow(unknown_lints, mismatched_lifetime_syntaxes)]
 pub fn add_start_slash<S: ?Sized + AsRef<str>>(s: &S) -> Cow<str> {
     let s = s.as_ref();
 
@@ -100,8 +101,8 @@ pub fn add_start_slash<S: ?Sized + AsRef<str>>(s: &S) -> Cow<str> {
 /// assert_eq!("/path", s);
 /// ```
 #[inline]
-pub fn add_start_slash_in_place(s: &mut String) {
-    if !s.starts_with('/') {
+pub fn add_start_slash_in_place<B: SeparatorBuf>(s: &mut B) {
+    if !s.as_ref().starts_with('/') {
         s.insert(0, '/');
     }
 }
@@ -112,6 +113,7 @@ pub fn add_start_slash_in_place(s: &mut String) {
 /// assert_eq!("path/", slash_formatter::add_end_slash("path"));
 /// ```
 #[inline]
+#[allow(unknown_lints, mismatched_lifetime_syntaxes)]
 pub fn add_end_slash<S: ?Sized + AsRef<str>>(s: &S) -> Cow<str> {
     let s = s.as_ref();
 
@@ -132,8 +134,8 @@ pub fn add_end_slash<S: ?Sized + AsRef<str>>(s: &S) -> Cow<str> {
 /// assert_eq!("path/", s);
 /// ```
 #[inline]
-pub fn add_end_slash_in_place(s: &mut String) {
-    if !s.ends_with('/') {
+pub fn add_end_slash_in_place<B: SeparatorBuf>(s: &mut B) {
+    if !s.as_ref().ends_with('/') {
         s.push('/');
     }
 }
@@ -162,12 +164,25 @@ pub fn concat_with_slash<S1: Into<String>, S2: AsRef<str>>(s1: S1, s2: S2) -> St
 /// assert_eq!("path/to", s);
 /// ```
 #[inline]
-pub fn concat_with_slash_in_place<S2: AsRef<str>>(s1: &mut String, s2: S2) {
+pub fn concat_with_slash_in_place<B: SeparatorBuf, S2: AsRef<str>>(s1: &mut B, s2: S2) {
     add_end_slash_in_place(s1);
     s1.push_str(delete_start_slash(s2.as_ref()));
     delete_end_slash_in_place(s1);
 }
 
+/// Create an iterator over the segments of a string separated by slashes.
+///
+/// ```
+/// let segments: Vec<&str> =
+///     slash_formatter::slash_segments("/path/to/").collect();
+///
+/// assert_eq!(vec!["path", "to"], segments);
+/// ```
+#[inline]
+pub fn slash_segments<S: ?Sized + AsRef<str>>(s: &S) -> Segments<'_, Slash> {
+    Segments::new(s.as_ref())
+}
+
 /**
 Concatenate multiple strings with slashes. It can also be used to get the literal `'/'`.
 
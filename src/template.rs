@@ -0,0 +1,399 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display, Formatter};
+
+use crate::percent;
+
+/// Errors which can occur when parsing or expanding a `SlashTemplate`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TemplateError {
+    /// A `{` in the segment at the byte offset is not closed.
+    UnclosedBrace { offset: usize },
+    /// The segment at the byte offset is not a valid parameter, such as `{}` or `a{b}`.
+    InvalidParameter { offset: usize },
+    /// The wildcard parameter at the byte offset is not the last segment.
+    WildcardNotLast { offset: usize },
+    /// The parameter at the byte offset has the same name as an earlier one.
+    DuplicateParameter { offset: usize },
+    /// No value is given for the parameter when expanding.
+    MissingParameter { name: String },
+    /// The value for the parameter is empty, `.` or `..`, or the value for the wildcard parameter has a `.` or `..` segment, when expanding.
+    InvalidValue { name: String },
+}
+
+impl Display for TemplateError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::UnclosedBrace {
+                offset,
+            } => write!(f, "the `{{` at {} is not closed", offset),
+            TemplateError::InvalidParameter {
+                offset,
+            } => write!(f, "an invalid parameter at {}", offset),
+            TemplateError::WildcardNotLast {
+                offset,
+            } => write!(f, "the wildcard parameter at {} is not the last segment", offset),
+            TemplateError::DuplicateParameter {
+                offset,
+            } => write!(f, "a duplicate parameter at {}", offset),
+            TemplateError::MissingParameter {
+                name,
+            } => write!(f, "no value for the parameter {:?}", name),
+            TemplateError::InvalidValue {
+                name,
+            } => write!(f, "an invalid value for the parameter {:?}", name),
+        }
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for TemplateError {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum TemplateSegment {
+    Literal(String),
+    Parameter(String),
+    Wildcard(String),
+}
+
+/// A route template separated by slashes, such as `/users/{id}/posts/{post}` or `/static/{*path}`.
+///
+/// A segment in braces is a named parameter which matches exactly one segment. A segment in braces whose name starts with `*` is a wildcard parameter which matches the rest of the path, including none, and it must be the last segment. Other segments are literals. An ending slash is ignored, following the rules of `delete_end_slash`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SlashTemplate {
+    template: String,
+    rooted:   bool,
+    segments: Vec<TemplateSegment>,
+}
+
+impl SlashTemplate {
+    /// Parse a template.
+    ///
+    /// ```
+    /// use slash_formatter::{SlashTemplate, TemplateError};
+    ///
+    /// assert!(SlashTemplate::new("/users/{id}/posts/{post}").is_ok());
+    ///
+    /// assert_eq!(
+    ///     Err(TemplateError::WildcardNotLast {
+    ///         offset: 1
+    ///     }),
+    ///     SlashTemplate::new("/{*rest}/x")
+    /// );
+    /// ```
+    pub fn new<S: ?Sized + AsRef<str>>(template: &S) -> Result<Self, TemplateError> {
+        let template = template.as_ref();
+
+        let trimmed = crate::delete_end_slash(template);
+        let rooted = trimmed.starts_with('/');
+        let body = if rooted { &trimmed[1..] } else { trimmed };
+
+        let mut segments: Vec<TemplateSegment> = Vec::new();
+
+        if !body.is_empty() {
+            let mut offset = rooted as usize;
+            let mut wildcard_offset = None;
+
+            for raw in body.split('/') {
+                if let Some(offset) = wildcard_offset {
+                    return Err(TemplateError::WildcardNotLast {
+                        offset,
+                    });
+                }
+
+                let segment = if let Some(inner) = raw.strip_prefix('{') {
+                    let inner = match inner.strip_suffix('}') {
+                        Some(inner) => inner,
+                        None if inner.contains('}') => {
+                            return Err(TemplateError::InvalidParameter {
+                                offset,
+                            })
+                        },
+                        None => {
+                            return Err(TemplateError::UnclosedBrace {
+                                offset,
+                            })
+                        },
+                    };
+
+                    let (name, wildcard) = match inner.strip_prefix('*') {
+                        Some(name) => (name, true),
+                        None => (inner, false),
+                    };
+
+                    if name.is_empty() || name.contains(|c| c == '{' || c == '}' || c == '*') {
+                        return Err(TemplateError::InvalidParameter {
+                            offset,
+                        });
+                    }
+
+                    let duplicate = segments.iter().any(|segment| match segment {
+                        TemplateSegment::Parameter(n) | TemplateSegment::Wildcard(n) => n == name,
+                        TemplateSegment::Literal(_) => false,
+                    });
+
+                    if duplicate {
+                        return Err(TemplateError::DuplicateParameter {
+                            offset,
+                        });
+                    }
+
+                    if wildcard {
+                        wildcard_offset = Some(offset);
+
+                        TemplateSegment::Wildcard(String::from(name))
+                    } else {
+                        TemplateSegment::Parameter(String::from(name))
+                    }
+                } else if raw.contains(|c| c == '{' || c == '}') {
+                    return Err(TemplateError::InvalidParameter {
+                        offset,
+                    });
+                } else {
+                    TemplateSegment::Literal(String::from(raw))
+                };
+
+                segments.push(segment);
+
+                offset += raw.len() + 1;
+            }
+        }
+
+        Ok(SlashTemplate {
+            template: String::from(template),
+            rooted,
+            segments,
+        })
+    }
+
+    /// Get the source template.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.template
+    }
+
+    /// Create an iterator over the names of the parameters, in order.
+    ///
+    /// ```
+    /// use slash_formatter::SlashTemplate;
+    ///
+    /// let template = SlashTemplate::new("/users/{id}/files/{*path}").unwrap();
+    ///
+    /// assert_eq!(vec!["id", "path"], template.parameters().collect::<Vec<_>>());
+    /// ```
+    #[inline]
+    pub fn parameters(&self) -> impl Iterator<Item = &str> {
+        self.segments.iter().filter_map(|segment| match segment {
+            TemplateSegment::Parameter(name) | TemplateSegment::Wildcard(name) => {
+                Some(name.as_str())
+            },
+            TemplateSegment::Literal(_) => None,
+        })
+    }
+
+    /// Expand this template into a path. Values of parameters are looked up by names and percent-encoded, and a value of a wildcard parameter keeps its slashes. The segments are joined with the rules of `concat_with_slash_in_place`. A value of a named parameter must not be empty, `.` or `..`, and no segment of a value of a wildcard parameter may be `.` or `..`, so that the path can be matched back by `captures`. Leading slashes of a value of a wildcard parameter are removed.
+    ///
+    /// ```
+    /// use slash_formatter::{SlashTemplate, TemplateError};
+    ///
+    /// let template = SlashTemplate::new("/users/{id}/files/{*path}").unwrap();
+    ///
+    /// let params = [("id", "a b"), ("path", "/docs/x?.txt/")];
+    ///
+    /// let lookup =
+    ///     |name: &str| params.iter().find(|(n, _)| *n == name).map(|(_, v)| *v);
+    ///
+    /// assert_eq!(
+    ///     Ok(String::from("/users/a%20b/files/docs/x%3F.txt")),
+    ///     template.expand(lookup)
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Err(TemplateError::MissingParameter {
+    ///         name: String::from("id")
+    ///     }),
+    ///     template.expand(|_| None::<&str>)
+    /// );
+    ///
+    /// for value in ["", ".", ".."] {
+    ///     assert_eq!(
+    ///         Err(TemplateError::InvalidValue {
+    ///             name: String::from("id")
+    ///         }),
+    ///         template.expand(|name: &str| if name == "id" {
+    ///             Some(value)
+    ///         } else {
+    ///             Some("x")
+    ///         })
+    ///     );
+    /// }
+    ///
+    /// let template = SlashTemplate::new("/static/{*path}").unwrap();
+    ///
+    /// assert_eq!(
+    ///     Err(TemplateError::InvalidValue {
+    ///         name: String::from("path")
+    ///     }),
+    ///     template.expand(|_| Some("../../etc/passwd"))
+    /// );
+    ///
+    /// let template = SlashTemplate::new("{*path}").unwrap();
+    ///
+    /// let path = template.expand(|_| Some("/etc/passwd")).unwrap();
+    ///
+    /// assert_eq!("etc/passwd", path);
+    /// assert_eq!(
+    ///     Some(vec![("path", String::from("etc/passwd"))]),
+    ///     template.captures(&path)
+    /// );
+    /// ```
+    pub fn expand<F: FnMut(&str) -> Option<V>, V: AsRef<str>>(
+        &self,
+        mut lookup: F,
+    ) -> Result<String, TemplateError> {
+        let mut s = String::with_capacity(self.template.len());
+
+        for segment in self.segments.iter() {
+            let mut encoded = String::new();
+
+            let value: &str = match segment {
+                TemplateSegment::Literal(literal) => literal,
+                TemplateSegment::Parameter(name) | TemplateSegment::Wildcard(name) => {
+                    let value = lookup(name).ok_or_else(|| TemplateError::MissingParameter {
+                        name: name.clone(),
+                    })?;
+
+                    if let TemplateSegment::Wildcard(_) = segment {
+                        // a leading slash would make a non-rooted template produce a rooted path
+                        let value = value.as_ref().trim_start_matches('/');
+
+                        for (index, part) in value.split('/').enumerate() {
+                            if matches!(part, "." | "..") {
+                                return Err(TemplateError::InvalidValue {
+                                    name: name.clone()
+                                });
+                            }
+
+                            if index > 0 {
+                                encoded.push('/');
+                            }
+
+                            percent::push_encoded_segment(&mut encoded, part);
+                        }
+                    } else {
+                        let value = value.as_ref();
+
+                        if matches!(value, "" | "." | "..") {
+                            return Err(TemplateError::InvalidValue {
+                                name: name.clone()
+                            });
+                        }
+
+                        percent::push_encoded_segment(&mut encoded, value);
+                    }
+
+                    &encoded
+                },
+            };
+
+            if s.is_empty() && !self.rooted {
+                s.push_str(crate::delete_end_slash(value));
+            } else {
+                crate::concat_with_slash_in_place(&mut s, value);
+            }
+        }
+
+        if s.is_empty() && self.rooted {
+            s.push('/');
+        }
+
+        Ok(s)
+    }
+
+    /// Match a path against this template and extract the percent-decoded values of the parameters, in order. An ending slash of the path is ignored.
+    ///
+    /// ```
+    /// use slash_formatter::SlashTemplate;
+    ///
+    /// let template = SlashTemplate::new("/users/{id}/files/{*path}").unwrap();
+    ///
+    /// assert_eq!(
+    ///     Some(vec![
+    ///         ("id", String::from("a b")),
+    ///         ("path", String::from("docs/x.txt"))
+    ///     ]),
+    ///     template.captures("/users/a%20b/files/docs/x.txt/")
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Some(vec![("id", String::from("42")), ("path", String::new())]),
+    ///     template.captures("/users/42/files")
+    /// );
+    ///
+    /// assert_eq!(None, template.captures("/users/42/posts/1"));
+    /// ```
+    pub fn captures<S: ?Sized + AsRef<str>>(&self, path: &S) -> Option<Vec<(&str, String)>> {
+        let path = crate::delete_end_slash(path.as_ref());
+
+        if path.starts_with('/') != self.rooted {
+            return None;
+        }
+
+        let mut parts = crate::slash_segments(path);
+        let mut captures = Vec::new();
+
+        for segment in self.segments.iter() {
+            match segment {
+                TemplateSegment::Literal(literal) => {
+                    if parts.next()? != literal {
+                        return None;
+                    }
+                },
+                TemplateSegment::Parameter(name) => {
+                    let part = parts.next()?;
+
+                    if part.is_empty() {
+                        return None;
+                    }
+
+                    captures.push((name.as_str(), percent::decode(part).ok()?.into_owned()));
+                },
+                TemplateSegment::Wildcard(name) => {
+                    let rest = parts.as_str();
+
+                    captures.push((name.as_str(), percent::decode(rest).ok()?.into_owned()));
+
+                    return Some(captures);
+                },
+            }
+        }
+
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(captures)
+    }
+
+    /// Check whether a path matches this template.
+    ///
+    /// ```
+    /// use slash_formatter::SlashTemplate;
+    ///
+    /// let template = SlashTemplate::new("/users/{id}").unwrap();
+    ///
+    /// assert!(template.is_match("/users/42/"));
+    /// assert!(!template.is_match("/users"));
+    /// ```
+    #[inline]
+    pub fn is_match<S: ?Sized + AsRef<str>>(&self, path: &S) -> bool {
+        self.captures(path).is_some()
+    }
+}
+
+impl Display for SlashTemplate {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.template)
+    }
+}
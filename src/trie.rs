@@ -0,0 +1,330 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::{iter::FromIterator, marker::PhantomData};
+
+use crate::{Backslash, FileSeparator, Segments, Separator, Slash};
+
+#[derive(Debug, Clone)]
+struct Node<V> {
+    value:    Option<V>,
+    children: BTreeMap<String, Node<V>>,
+}
+
+impl<V> Node<V> {
+    #[inline]
+    fn new() -> Self {
+        Node {
+            value: None, children: BTreeMap::new()
+        }
+    }
+}
+
+fn remove<S: Separator, V>(node: &mut Node<V>, segments: &mut Segments<'_, S>) -> Option<V> {
+    match segments.next() {
+        None => node.value.take(),
+        Some(segment) => {
+            let child = node.children.get_mut(segment)?;
+
+            let value = remove(child, segments);
+
+            if child.value.is_none() && child.children.is_empty() {
+                node.children.remove(segment);
+            }
+
+            value
+        },
+    }
+}
+
+/// A prefix tree which stores values by the segments of strings separated by `S`.
+///
+/// Keys are split with `Segments`, so a starting separator and an ending separator do not matter. For example, `"/a/b/"`, `"/a/b"` and `"a/b"` are the same key.
+#[derive(Debug, Clone)]
+pub struct SeparatorTrie<S: Separator, V> {
+    root:       Node<V>,
+    len:        usize,
+    _separator: PhantomData<S>,
+}
+
+/// A prefix tree which stores values by the segments of strings separated by slashes.
+pub type SlashTrie<V> = SeparatorTrie<Slash, V>;
+
+/// A prefix tree which stores values by the segments of strings separated by backslashes.
+pub type BackslashTrie<V> = SeparatorTrie<Backslash, V>;
+
+/// A prefix tree which stores values by the segments of strings separated by `FILE_SEPARATOR`.
+pub type FileSeparatorTrie<V> = SeparatorTrie<FileSeparator, V>;
+
+impl<S: Separator, V> SeparatorTrie<S, V> {
+    /// Create an empty trie.
+    #[inline]
+    pub fn new() -> Self {
+        SeparatorTrie {
+            root: Node::new(), len: 0, _separator: PhantomData
+        }
+    }
+
+    /// Get the number of values.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether there are no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    fn node(&self, key: &str) -> Option<&Node<V>> {
+        let mut node = &self.root;
+
+        for segment in Segments::<S>::new(key) {
+            node = node.children.get(segment)?;
+        }
+
+        Some(node)
+    }
+
+    /// Insert a value. Returns the old value of the key if any.
+    ///
+    /// ```
+    /// use slash_formatter::SlashTrie;
+    ///
+    /// let mut trie = SlashTrie::new();
+    ///
+    /// assert_eq!(None, trie.insert("/a/b/", 1));
+    /// assert_eq!(Some(1), trie.insert("/a/b", 2));
+    /// assert_eq!(1, trie.len());
+    /// ```
+    pub fn insert<K: AsRef<str>>(&mut self, key: K, value: V) -> Option<V> {
+        let mut node = &mut self.root;
+
+        for segment in Segments::<S>::new(key.as_ref()) {
+            node = node.children.entry(String::from(segment)).or_insert_with(Node::new);
+        }
+
+        let old = node.value.replace(value);
+
+        if old.is_none() {
+            self.len += 1;
+        }
+
+        old
+    }
+
+    /// Get the value of a key.
+    ///
+    /// ```
+    /// use slash_formatter::SlashTrie;
+    ///
+    /// let mut trie = SlashTrie::new();
+    ///
+    /// trie.insert("/a/b", 1);
+    ///
+    /// assert_eq!(Some(&1), trie.get("a/b/"));
+    /// assert_eq!(None, trie.get("/a"));
+    /// ```
+    #[inline]
+    pub fn get<K: AsRef<str>>(&self, key: K) -> Option<&V> {
+        self.node(key.as_ref())?.value.as_ref()
+    }
+
+    /// Get the mutable value of a key.
+    #[inline]
+    pub fn get_mut<K: AsRef<str>>(&mut self, key: K) -> Option<&mut V> {
+        let mut node = &mut self.root;
+
+        for segment in Segments::<S>::new(key.as_ref()) {
+            node = node.children.get_mut(segment)?;
+        }
+
+        node.value.as_mut()
+    }
+
+    /// Check whether a key has a value.
+    #[inline]
+    pub fn contains_key<K: AsRef<str>>(&self, key: K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Remove the value of a key and return it.
+    ///
+    /// ```
+    /// use slash_formatter::SlashTrie;
+    ///
+    /// let mut trie = SlashTrie::new();
+    ///
+    /// trie.insert("/a/b", 1);
+    ///
+    /// assert_eq!(Some(1), trie.remove("/a/b/"));
+    /// assert!(trie.is_empty());
+    /// ```
+    #[inline]
+    pub fn remove<K: AsRef<str>>(&mut self, key: K) -> Option<V> {
+        let value = remove(&mut self.root, &mut Segments::<S>::new(key.as_ref()));
+
+        if value.is_some() {
+            self.len -= 1;
+        }
+
+        value
+    }
+
+    /// Find the longest key which is a prefix of the given key, segment by segment. Returns the matched part of the given key and the value.
+    ///
+    /// ```
+    /// use slash_formatter::SlashTrie;
+    ///
+    /// let mut trie = SlashTrie::new();
+    ///
+    /// trie.insert("/", "root");
+    /// trie.insert("/static", "static");
+    /// trie.insert("/static/images", "images");
+    ///
+    /// assert_eq!(
+    ///     Some(("/static/images", &"images")),
+    ///     trie.longest_prefix("/static/images/a.png")
+    /// );
+    /// assert_eq!(
+    ///     Some(("/static", &"static")),
+    ///     trie.longest_prefix("/static/imagesx")
+    /// );
+    /// assert_eq!(Some(("/", &"root")), trie.longest_prefix("/api"));
+    /// ```
+    pub fn longest_prefix<'k>(&self, key: &'k str) -> Option<(&'k str, &V)> {
+        let root_length = if key.starts_with(S::SEPARATOR) { S::SEPARATOR.len_utf8() } else { 0 };
+
+        let mut node = &self.root;
+        let mut longest = node.value.as_ref().map(|value| (root_length, value));
+
+        for segment in Segments::<S>::new(key) {
+            node = match node.children.get(segment) {
+                Some(child) => child,
+                None => break,
+            };
+
+            if let Some(value) = node.value.as_ref() {
+                let end = segment.as_ptr() as usize - key.as_ptr() as usize + segment.len();
+
+                longest = Some((end, value));
+            }
+        }
+
+        longest.map(|(end, value)| (&key[..end], value))
+    }
+
+    /// Create an iterator over the keys and the values under a key, including the key itself, in order. The keys are joined with the separator, without a starting separator.
+    ///
+    /// ```
+    /// use slash_formatter::SlashTrie;
+    ///
+    /// let mut trie = SlashTrie::new();
+    ///
+    /// trie.insert("/a", 1);
+    /// trie.insert("/a/b/c", 2);
+    /// trie.insert("/a/d", 3);
+    /// trie.insert("/e", 4);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         (String::from("a"), &1),
+    ///         (String::from("a/b/c"), &2),
+    ///         (String::from("a/d"), &3)
+    ///     ],
+    ///     trie.iter_prefix("/a/").collect::<Vec<_>>()
+    /// );
+    /// ```
+    pub fn iter_prefix<K: AsRef<str>>(&self, key: K) -> SeparatorTrieIter<'_, S, V> {
+        let key = key.as_ref();
+
+        let mut stack = Vec::new();
+
+        if let Some(node) = self.node(key) {
+            let mut prefix = String::new();
+
+            for segment in Segments::<S>::new(key) {
+                if !prefix.is_empty() {
+                    prefix.push(S::SEPARATOR);
+                }
+
+                prefix.push_str(segment);
+            }
+
+            stack.push((prefix, node));
+        }
+
+        SeparatorTrieIter {
+            stack,
+            _separator: PhantomData,
+        }
+    }
+
+    /// Create an iterator over all the keys and the values, in order.
+    #[inline]
+    pub fn iter(&self) -> SeparatorTrieIter<'_, S, V> {
+        self.iter_prefix("")
+    }
+}
+
+impl<S: Separator, V> Default for SeparatorTrie<S, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Separator, K: AsRef<str>, V> FromIterator<(K, V)> for SeparatorTrie<S, V> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut trie = Self::new();
+
+        trie.extend(iter);
+
+        trie
+    }
+}
+
+impl<S: Separator, K: AsRef<str>, V> Extend<(K, V)> for SeparatorTrie<S, V> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+/// An iterator over the keys and the values of a `SeparatorTrie`.
+#[derive(Debug, Clone)]
+pub struct SeparatorTrieIter<'a, S: Separator, V> {
+    stack:      Vec<(String, &'a Node<V>)>,
+    _separator: PhantomData<S>,
+}
+
+impl<'a, S: Separator, V> Iterator for SeparatorTrieIter<'a, S, V> {
+    type Item = (String, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((key, node)) = self.stack.pop() {
+            for (segment, child) in node.children.iter().rev() {
+                let mut child_key = String::with_capacity(key.len() + 1 + segment.len());
+
+                child_key.push_str(&key);
+
+                if !key.is_empty() {
+                    child_key.push(S::SEPARATOR);
+                }
+
+                child_key.push_str(segment);
+
+                self.stack.push((child_key, child));
+            }
+
+            if let Some(value) = node.value.as_ref() {
+                return Some((key, value));
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,86 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+#[inline]
+pub(crate) fn delete_end<T: Copy + PartialEq>(s: &[T], separator: T) -> &[T] {
+    let length = s.len();
+
+    if length > 1 && s[length - 1] == separator {
+        &s[..length - 1]
+    } else {
+        s
+    }
+}
+
+#[inline]
+pub(crate) fn delete_end_in_place<T: Copy + PartialEq>(s: &mut Vec<T>, separator: T) {
+    let length = s.len();
+
+    if length > 1 && s[length - 1] == separator {
+        s.truncate(length - 1);
+    }
+}
+
+#[inline]
+pub(crate) fn delete_start<T: Copy + PartialEq>(s: &[T], separator: T) -> &[T] {
+    if s.len() > 1 && s[0] == separator {
+        &s[1..]
+    } else {
+        s
+    }
+}
+
+#[inline]
+pub(crate) fn delete_start_in_place<T: Copy + PartialEq>(s: &mut Vec<T>, separator: T) {
+    if s.len() > 1 && s[0] == separator {
+        s.remove(0);
+    }
+}
+
+#[inline]
+pub(crate) fn add_start<T: Copy + PartialEq>(s: &[T], separator: T) -> Cow<'_, [T]> {
+    if s.first() == Some(&separator) {
+        Cow::from(s)
+    } else {
+        let mut v = Vec::with_capacity(s.len() + 1);
+
+        v.push(separator);
+        v.extend_from_slice(s);
+
+        Cow::from(v)
+    }
+}
+
+#[inline]
+pub(crate) fn add_start_in_place<T: Copy + PartialEq>(s: &mut Vec<T>, separator: T) {
+    if s.first() != Some(&separator) {
+        s.insert(0, separator);
+    }
+}
+
+#[inline]
+pub(crate) fn add_end<T: Copy + PartialEq>(s: &[T], separator: T) -> Cow<'_, [T]> {
+    if s.last() == Some(&separator) {
+        Cow::from(s)
+    } else {
+        let mut v = Vec::with_capacity(s.len() + 1);
+
+        v.extend_from_slice(s);
+        v.push(separator);
+
+        Cow::from(v)
+    }
+}
+
+#[inline]
+pub(crate) fn add_end_in_place<T: Copy + PartialEq>(s: &mut Vec<T>, separator: T) {
+    if s.last() != Some(&separator) {
+        s.push(separator);
+    }
+}
+
+#[inline]
+pub(crate) fn concat_with_in_place<T: Copy + PartialEq>(s1: &mut Vec<T>, s2: &[T], separator: T) {
+    add_end_in_place(s1, separator);
+    s1.extend_from_slice(delete_start(s2, separator));
+    delete_end_in_place(s1, separator);
+}
@@ -0,0 +1,233 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::windows;
+
+/// Errors which can occur when validating a string separated by slashes or backslashes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PathFormatError {
+    /// An empty segment between two consecutive separators at the byte offset.
+    EmptySegment { offset: usize },
+    /// A character which is not allowed at the byte offset.
+    DisallowedCharacter { offset: usize, character: char },
+    /// A reserved name, such as `CON` on Windows, starting at the byte offset.
+    ReservedName { offset: usize },
+    /// A segment starting at the byte offset is longer than the limit.
+    SegmentTooLong { offset: usize, length: usize },
+    /// A separator of the other kind at the byte offset.
+    MixedSeparator { offset: usize },
+}
+
+impl Display for PathFormatError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PathFormatError::EmptySegment {
+                offset,
+            } => write!(f, "an empty segment at {}", offset),
+            PathFormatError::DisallowedCharacter {
+                offset,
+                character,
+            } => write!(f, "a disallowed character {:?} at {}", character, offset),
+            PathFormatError::ReservedName {
+                offset,
+            } => write!(f, "a reserved name at {}", offset),
+            PathFormatError::SegmentTooLong {
+                offset,
+                length,
+            } => write!(
+                f,
+                "the segment at {} is too long ({} > {})",
+                offset,
+                length,
+                windows::MAX_SEGMENT_LENGTH
+            ),
+            PathFormatError::MixedSeparator {
+                offset,
+            } => write!(f, "a mixed separator at {}", offset),
+        }
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for PathFormatError {}
+
+fn validate_segment(
+    segment: &str,
+    offset: usize,
+    other_separator: char,
+    windows: bool,
+    first: bool,
+) -> Result<(), PathFormatError> {
+    if segment.is_empty() {
+        return Err(PathFormatError::EmptySegment {
+            offset,
+        });
+    }
+
+    let drive = windows && first && windows::has_drive_letter(segment);
+
+    for (index, c) in segment.char_indices() {
+        if c == other_separator {
+            return Err(PathFormatError::MixedSeparator {
+                offset: offset + index
+            });
+        }
+
+        let disallowed =
+            if windows { windows::is_illegal_char(c) && !(drive && index == 1) } else { c == '\0' };
+
+        if disallowed {
+            return Err(PathFormatError::DisallowedCharacter {
+                offset:    offset + index,
+                character: c,
+            });
+        }
+    }
+
+    if windows {
+        if segment != "." && segment != ".." {
+            if let Some(c) = segment.chars().next_back() {
+                if c == '.' || c == ' ' {
+                    return Err(PathFormatError::DisallowedCharacter {
+                        offset:    offset + segment.len() - 1,
+                        character: c,
+                    });
+                }
+            }
+        }
+
+        if windows::is_reserved_name(segment) {
+            return Err(PathFormatError::ReservedName {
+                offset,
+            });
+        }
+    }
+
+    let length = if windows { segment.encode_utf16().count() } else { segment.len() };
+
+    if length > windows::MAX_SEGMENT_LENGTH {
+        return Err(PathFormatError::SegmentTooLong {
+            offset,
+            length,
+        });
+    }
+
+    Ok(())
+}
+
+fn validate(
+    s: &str,
+    separator: char,
+    other_separator: char,
+    windows: bool,
+) -> Result<(), PathFormatError> {
+    let start = if windows && (s.starts_with("\\\\?\\") || s.starts_with("\\\\.\\")) {
+        4
+    } else if windows && windows::has_unc_prefix(s) {
+        2
+    } else if s.starts_with(separator) {
+        1
+    } else {
+        0
+    };
+
+    let body = &s[start..];
+    let body = body.strip_suffix(separator).unwrap_or(body);
+
+    if body.is_empty() {
+        return Ok(());
+    }
+
+    let mut offset = start;
+
+    for (index, segment) in body.split(separator).enumerate() {
+        validate_segment(segment, offset, other_separator, windows, index == 0)?;
+
+        offset += segment.len() + separator.len_utf8();
+    }
+
+    Ok(())
+}
+
+/// Validate a string separated by slashes. Empty segments, NUL characters, backslashes and segments longer than 255 bytes are rejected. A starting slash and an ending slash are allowed.
+///
+/// ```
+/// use slash_formatter::PathFormatError;
+///
+/// assert_eq!(Ok(()), slash_formatter::validate_slash("/path/to/"));
+///
+/// assert_eq!(
+///     Err(PathFormatError::EmptySegment {
+///         offset: 6
+///     }),
+///     slash_formatter::validate_slash("/path//to")
+/// );
+///
+/// assert_eq!(
+///     Err(PathFormatError::MixedSeparator {
+///         offset: 5
+///     }),
+///     slash_formatter::validate_slash("/path\\to")
+/// );
+/// ```
+#[inline]
+pub fn validate_slash<S: ?Sized + AsRef<str>>(s: &S) -> Result<(), PathFormatError> {
+    validate(s.as_ref(), '/', '\\', false)
+}
+
+/// Validate a string separated by backslashes with the rules of Windows. Empty segments, slashes, the characters `<>:"|?*`, control characters, segments ending with `.` or a space, reserved device names and segments longer than 255 UTF-16 code units are rejected. Drive letters and UNC prefixes are allowed, and so are a starting backslash and an ending backslash.
+///
+/// ```
+/// use slash_formatter::PathFormatError;
+///
+/// assert_eq!(Ok(()), slash_formatter::validate_backslash("C:\\path\\to\\"));
+/// assert_eq!(
+///     Ok(()),
+///     slash_formatter::validate_backslash("\\\\server\\share")
+/// );
+///
+/// assert_eq!(
+///     Err(PathFormatError::DisallowedCharacter {
+///         offset:    7,
+///         character: '?',
+///     }),
+///     slash_formatter::validate_backslash("C:\\path?")
+/// );
+///
+/// assert_eq!(
+///     Err(PathFormatError::ReservedName {
+///         offset: 3
+///     }),
+///     slash_formatter::validate_backslash("C:\\aux.txt")
+/// );
+/// ```
+#[inline]
+pub fn validate_backslash<S: ?Sized + AsRef<str>>(s: &S) -> Result<(), PathFormatError> {
+    validate(s.as_ref(), '\\', '/', true)
+}
+
+/// Validate a string separated by `FILE_SEPARATOR`, with the rules of `validate_slash` or `validate_backslash`.
+///
+/// ```
+/// assert!(slash_formatter::validate_file_separator(
+///     slash_formatter::concat_with_file_separator!("path", "to")
+/// )
+/// .is_ok());
+///
+/// assert!(slash_formatter::validate_file_separator(
+///     slash_formatter::concat_with_file_separator!("path", "", "to")
+/// )
+/// .is_err());
+/// ```
+#[inline]
+pub fn validate_file_separator<S: ?Sized + AsRef<str>>(s: &S) -> Result<(), PathFormatError> {
+    #[cfg(unix)]
+    {
+        validate_slash(s)
+    }
+
+    #[cfg(windows)]
+    {
+        validate_backslash(s)
+    }
+}
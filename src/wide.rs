@@ -0,0 +1,981 @@
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::{
+    fmt::{self, Display, Formatter},
+    iter::FusedIterator,
+};
+
+use crate::units::{
+    add_end, add_end_in_place, add_start, add_start_in_place, concat_with_in_place, delete_end,
+    delete_end_in_place, delete_start, delete_start_in_place,
+};
+
+/// Errors which can occur when converting UTF-16 code units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WideError {
+    /// The code unit at the index is an unpaired surrogate, which cannot be represented in a `String`.
+    UnpairedSurrogate { index: usize },
+    /// The bytes at the byte offset are not valid WTF-8.
+    InvalidWtf8 { offset: usize },
+}
+
+impl Display for WideError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            WideError::UnpairedSurrogate {
+                index,
+            } => write!(f, "an unpaired surrogate at {}", index),
+            WideError::InvalidWtf8 {
+                offset,
+            } => write!(f, "invalid WTF-8 at {}", offset),
+        }
+    }
+}
+
+#[cfg(has_core_error)]
+impl core::error::Error for WideError {}
+
+/// Encode a string into UTF-16 code units.
+///
+/// ```
+/// assert_eq!(
+///     vec![0x61, 0x5C, 0xD83D, 0xDE00],
+///     slash_formatter::encode_wide("a\\\u{1F600}")
+/// );
+/// ```
+#[inline]
+pub fn encode_wide<S: ?Sized + AsRef<str>>(s: &S) -> Vec<u16> {
+    s.as_ref().encode_utf16().collect()
+}
+
+/// Decode UTF-16 code units into a string. Use `wide_to_wtf8` instead to keep unpaired surrogates.
+///
+/// ```
+/// use slash_formatter::WideError;
+///
+/// assert_eq!(
+///     Ok(String::from("a\\b")),
+///     slash_formatter::decode_wide(&[0x61, 0x5C, 0x62])
+/// );
+///
+/// assert_eq!(
+///     Err(WideError::UnpairedSurrogate {
+///         index: 1
+///     }),
+///     slash_formatter::decode_wide(&[0x61, 0xD800])
+/// );
+/// ```
+pub fn decode_wide<S: ?Sized + AsRef<[u16]>>(s: &S) -> Result<String, WideError> {
+    let s = s.as_ref();
+
+    let mut decoded = String::with_capacity(s.len());
+    let mut index = 0;
+
+    for c in char::decode_utf16(s.iter().copied()) {
+        match c {
+            Ok(c) => {
+                decoded.push(c);
+
+                index += c.len_utf16();
+            },
+            Err(_) => {
+                return Err(WideError::UnpairedSurrogate {
+                    index,
+                })
+            },
+        }
+    }
+
+    Ok(decoded)
+}
+
+/// Convert UTF-16 code units into WTF-8, which is UTF-8 extended to encode unpaired surrogates. This conversion is lossless, and the result is valid UTF-8 if the code units are valid UTF-16, so the `_bytes` functions can be applied to it.
+///
+/// ```
+/// assert_eq!(
+///     b"a\\\xED\xA0\x80".to_vec(),
+///     slash_formatter::wide_to_wtf8(&[0x61, 0x5C, 0xD800])
+/// );
+/// ```
+pub fn wide_to_wtf8<S: ?Sized + AsRef<[u16]>>(s: &S) -> Vec<u8> {
+    let s = s.as_ref();
+
+    let mut v = Vec::with_capacity(s.len());
+
+    for c in char::decode_utf16(s.iter().copied()) {
+        match c {
+            Ok(c) => {
+                let mut buffer = [0; 4];
+
+                v.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            },
+            Err(err) => {
+                let u = err.unpaired_surrogate();
+
+                v.push(0xE0 | (u >> 12) as u8);
+                v.push(0x80 | ((u >> 6) & 0x3F) as u8);
+                v.push(0x80 | (u & 0x3F) as u8);
+            },
+        }
+    }
+
+    v
+}
+
+/// Convert WTF-8 into UTF-16 code units. This is the inverse of `wide_to_wtf8`.
+///
+/// ```
+/// let wide = [0x61, 0x5C, 0xD800, 0xD83D, 0xDE00];
+///
+/// assert_eq!(
+///     Ok(wide.to_vec()),
+///     slash_formatter::wtf8_to_wide(&slash_formatter::wide_to_wtf8(&wide))
+/// );
+/// ```
+pub fn wtf8_to_wide<S: ?Sized + AsRef<[u8]>>(s: &S) -> Result<Vec<u16>, WideError> {
+    let s = s.as_ref();
+
+    let mut v = Vec::with_capacity(s.len());
+    let mut i = 0;
+
+    while i < s.len() {
+        let b = s[i];
+
+        let (length, initial, min) = match b {
+            0x00..=0x7F => (1, b as u32, 0),
+            0xC2..=0xDF => (2, (b & 0x1F) as u32, 0x80),
+            0xE0..=0xEF => (3, (b & 0x0F) as u32, 0x800),
+            0xF0..=0xF4 => (4, (b & 0x07) as u32, 0x10000),
+            _ => {
+                return Err(WideError::InvalidWtf8 {
+                    offset: i
+                })
+            },
+        };
+
+        let continuation = match s.get(i + 1..i + length) {
+            Some(continuation) if continuation.iter().all(|b| b & 0xC0 == 0x80) => continuation,
+            _ => {
+                return Err(WideError::InvalidWtf8 {
+                    offset: i
+                })
+            },
+        };
+
+        let code_point =
+            continuation.iter().fold(initial, |code_point, b| code_point << 6 | (b & 0x3F) as u32);
+
+        if code_point < min || code_point > 0x10FFFF {
+            return Err(WideError::InvalidWtf8 {
+                offset: i
+            });
+        }
+
+        if code_point >= 0x10000 {
+            let code_point = code_point - 0x10000;
+
+            v.push(0xD800 | (code_point >> 10) as u16);
+            v.push(0xDC00 | (code_point & 0x3FF) as u16);
+        } else {
+            v.push(code_point as u16);
+        }
+
+        i += length;
+    }
+
+    Ok(v)
+}
+
+/// An iterator over the segments of UTF-16 code units separated by a separator, with the same rules as `Segments`.
+#[derive(Debug, Clone)]
+pub struct WideSegments<'a> {
+    remaining: Option<&'a [u16]>,
+    separator: u16,
+}
+
+impl<'a> WideSegments<'a> {
+    #[inline]
+    fn new(s: &'a [u16], separator: u16) -> Self {
+        let s = delete_end(s, separator);
+
+        let remaining =
+            if s.is_empty() || s == [separator] { None } else { Some(delete_start(s, separator)) };
+
+        WideSegments {
+            remaining,
+            separator,
+        }
+    }
+
+    /// Get the part of the code units which has not been iterated yet.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [u16] {
+        self.remaining.unwrap_or(&[])
+    }
+}
+
+impl<'a> Iterator for WideSegments<'a> {
+    type Item = &'a [u16];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining?;
+
+        match remaining.iter().position(|&u| u == self.separator) {
+            Some(index) => {
+                self.remaining = Some(&remaining[index + 1..]);
+
+                Some(&remaining[..index])
+            },
+            None => {
+                self.remaining = None;
+
+                Some(remaining)
+            },
+        }
+    }
+}
+
+impl<'a> DoubleEndedIterator for WideSegments<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining?;
+
+        match remaining.iter().rposition(|&u| u == self.separator) {
+            Some(index) => {
+                self.remaining = Some(&remaining[..index]);
+
+                Some(&remaining[index + 1..])
+            },
+            None => {
+                self.remaining = None;
+
+                Some(remaining)
+            },
+        }
+    }
+}
+
+impl<'a> FusedIterator for WideSegments<'a> {}
+
+/// Delete an ending slash in UTF-16 code units except for just a slash.
+///
+/// ```
+/// let v = slash_formatter::encode_wide("path/");
+///
+/// assert_eq!(
+///     slash_formatter::encode_wide("path"),
+///     slash_formatter::delete_end_slash_wide(&v)
+/// );
+/// ```
+#[inline]
+pub fn delete_end_slash_wide<S: ?Sized + AsRef<[u16]>>(s: &S) -> &[u16] {
+    delete_end(s.as_ref(), b'/' as u16)
+}
+
+/// Delete an ending slash in UTF-16 code units except for just a slash.
+///
+/// ```
+/// let mut v = slash_formatter::encode_wide("path/");
+///
+/// slash_formatter::delete_end_slash_wide_in_place(&mut v);
+///
+/// assert_eq!(slash_formatter::encode_wide("path"), v);
+/// ```
+#[inline]
+pub fn delete_end_slash_wide_in_place(s: &mut Vec<u16>) {
+    delete_end_in_place(s, b'/' as u16)
+}
+
+/// Delete a starting slash in UTF-16 code units except for just a slash.
+///
+/// ```
+/// let v = slash_formatter::encode_wide("/path");
+///
+/// assert_eq!(
+///     slash_formatter::encode_wide("path"),
+///     slash_formatter::delete_start_slash_wide(&v)
+/// );
+/// ```
+#[inline]
+pub fn delete_start_slash_wide<S: ?Sized + AsRef<[u16]>>(s: &S) -> &[u16] {
+    delete_start(s.as_ref(), b'/' as u16)
+}
+
+/// Delete a starting slash in UTF-16 code units except for just a slash.
+///
+/// ```
+/// let mut v = slash_formatter::encode_wide("/path");
+///
+/// slash_formatter::delete_start_slash_wide_in_place(&mut v);
+///
+/// assert_eq!(slash_formatter::encode_wide("path"), v);
+/// ```
+#[inline]
+pub fn delete_start_slash_wide_in_place(s: &mut Vec<u16>) {
+    delete_start_in_place(s, b'/' as u16)
+}
+
+/// Add a starting slash into UTF-16 code units.
+///
+/// ```
+/// let v = slash_formatter::encode_wide("path");
+///
+/// assert_eq!(
+///     slash_formatter::encode_wide("/path"),
+///     slash_formatter::add_start_slash_wide(&v).as_ref()
+/// );
+/// ```
+#[inline]
+pub fn add_start_slash_wide<S: ?Sized + AsRef<[u16]>>(s: &S) -> Cow<'_, [u16]> {
+    add_start(s.as_ref(), b'/' as u16)
+}
+
+/// Add a starting slash into UTF-16 code units.
+///
+/// ```
+/// let mut v = slash_formatter::encode_wide("path");
+///
+/// slash_formatter::add_start_slash_wide_in_place(&mut v);
+///
+/// assert_eq!(slash_formatter::encode_wide("/path"), v);
+/// ```
+#[inline]
+pub fn add_start_slash_wide_in_place(s: &mut Vec<u16>) {
+    add_start_in_place(s, b'/' as u16)
+}
+
+/// Add an ending slash into UTF-16 code units.
+///
+/// ```
+/// let v = slash_formatter::encode_wide("path");
+///
+/// assert_eq!(
+///     slash_formatter::encode_wide("path/"),
+///     slash_formatter::add_end_slash_wide(&v).as_ref()
+/// );
+/// ```
+#[inline]
+pub fn add_end_slash_wide<S: ?Sized + AsRef<[u16]>>(s: &S) -> Cow<'_, [u16]> {
+    add_end(s.as_ref(), b'/' as u16)
+}
+
+/// Add an ending slash into UTF-16 code units.
+///
+/// ```
+/// let mut v = slash_formatter::encode_wide("path");
+///
+/// slash_formatter::add_end_slash_wide_in_place(&mut v);
+///
+/// assert_eq!(slash_formatter::encode_wide("path/"), v);
+/// ```
+#[inline]
+pub fn add_end_slash_wide_in_place(s: &mut Vec<u16>) {
+    add_end_in_place(s, b'/' as u16)
+}
+
+/// Concatenate two strings of UTF-16 code units with a slash. Unpaired surrogates are kept.
+///
+/// ```
+/// assert_eq!(
+///     vec![0x61, 0x2F, 0xD800],
+///     slash_formatter::concat_with_slash_wide(vec![0x61], [0xD800, 0x2F])
+/// );
+/// ```
+#[inline]
+pub fn concat_with_slash_wide<S1: Into<Vec<u16>>, S2: AsRef<[u16]>>(s1: S1, s2: S2) -> Vec<u16> {
+    let mut s1 = s1.into();
+
+    concat_with_slash_wide_in_place(&mut s1, s2);
+
+    s1
+}
+
+/// Concatenate two strings of UTF-16 code units with a slash. Unpaired surrogates are kept.
+///
+/// ```
+/// let mut v = slash_formatter::encode_wide("path");
+///
+/// slash_formatter::concat_with_slash_wide_in_place(
+///     &mut v,
+///     slash_formatter::encode_wide("to/"),
+/// );
+///
+/// assert_eq!(slash_formatter::encode_wide("path/to"), v);
+/// ```
+#[inline]
+pub fn concat_with_slash_wide_in_place<S2: AsRef<[u16]>>(s1: &mut Vec<u16>, s2: S2) {
+    concat_with_in_place(s1, s2.as_ref(), b'/' as u16)
+}
+
+/// Create an iterator over the segments of UTF-16 code units separated by slashes.
+///
+/// ```
+/// let v = slash_formatter::encode_wide("/path/to/");
+///
+/// let segments: Vec<String> = slash_formatter::slash_wide_segments(&v)
+///     .map(|segment| slash_formatter::decode_wide(segment).unwrap())
+///     .collect();
+///
+/// assert_eq!(vec!["path", "to"], segments);
+/// ```
+#[inline]
+pub fn slash_wide_segments<S: ?Sized + AsRef<[u16]>>(s: &S) -> WideSegments<'_> {
+    WideSegments::new(s.as_ref(), b'/' as u16)
+}
+
+/// Delete an ending backslash in UTF-16 code units except for just a backslash.
+///
+/// ```
+/// let v = slash_formatter::encode_wide("path\\");
+///
+/// assert_eq!(
+///     slash_formatter::encode_wide("path"),
+///     slash_formatter::delete_end_backslash_wide(&v)
+/// );
+/// ```
+#[inline]
+pub fn delete_end_backslash_wide<S: ?Sized + AsRef<[u16]>>(s: &S) -> &[u16] {
+    delete_end(s.as_ref(), b'\\' as u16)
+}
+
+/// Delete an ending backslash in UTF-16 code units except for just a backslash.
+///
+/// ```
+/// let mut v = slash_formatter::encode_wide("path\\");
+///
+/// slash_formatter::delete_end_backslash_wide_in_place(&mut v);
+///
+/// assert_eq!(slash_formatter::encode_wide("path"), v);
+/// ```
+#[inline]
+pub fn delete_end_backslash_wide_in_place(s: &mut Vec<u16>) {
+    delete_end_in_place(s, b'\\' as u16)
+}
+
+/// Delete a starting backslash in UTF-16 code units except for just a backslash.
+///
+/// ```
+/// let v = slash_formatter::encode_wide("\\path");
+///
+/// assert_eq!(
+///     slash_formatter::encode_wide("path"),
+///     slash_formatter::delete_start_backslash_wide(&v)
+/// );
+/// ```
+#[inline]
+pub fn delete_start_backslash_wide<S: ?Sized + AsRef<[u16]>>(s: &S) -> &[u16] {
+    delete_start(s.as_ref(), b'\\' as u16)
+}
+
+/// Delete a starting backslash in UTF-16 code units except for just a backslash.
+///
+/// ```
+/// let mut v = slash_formatter::encode_wide("\\path");
+///
+/// slash_formatter::delete_start_backslash_wide_in_place(&mut v);
+///
+/// assert_eq!(slash_formatter::encode_wide("path"), v);
+/// ```
+#[inline]
+pub fn delete_start_backslash_wide_in_place(s: &mut Vec<u16>) {
+    delete_start_in_place(s, b'\\' as u16)
+}
+
+/// Add a starting backslash into UTF-16 code units.
+///
+/// ```
+/// let v = slash_formatter::encode_wide("path");
+///
+/// assert_eq!(
+///     slash_formatter::encode_wide("\\path"),
+///     slash_formatter::add_start_backslash_wide(&v).as_ref()
+/// );
+/// ```
+#[inline]
+pub fn add_start_backslash_wide<S: ?Sized + AsRef<[u16]>>(s: &S) -> Cow<'_, [u16]> {
+    add_start(s.as_ref(), b'\\' as u16)
+}
+
+/// Add a starting backslash into UTF-16 code units.
+///
+/// ```
+/// let mut v = slash_formatter::encode_wide("path");
+///
+/// slash_formatter::add_start_backslash_wide_in_place(&mut v);
+///
+/// assert_eq!(slash_formatter::encode_wide("\\path"), v);
+/// ```
+#[inline]
+pub fn add_start_backslash_wide_in_place(s: &mut Vec<u16>) {
+    add_start_in_place(s, b'\\' as u16)
+}
+
+/// Add an ending backslash into UTF-16 code units.
+///
+/// ```
+/// let v = slash_formatter::encode_wide("path");
+///
+/// assert_eq!(
+///     slash_formatter::encode_wide("path\\"),
+///     slash_formatter::add_end_backslash_wide(&v).as_ref()
+/// );
+/// ```
+#[inline]
+pub fn add_end_backslash_wide<S: ?Sized + AsRef<[u16]>>(s: &S) -> Cow<'_, [u16]> {
+    add_end(s.as_ref(), b'\\' as u16)
+}
+
+/// Add an ending backslash into UTF-16 code units.
+///
+/// ```
+/// let mut v = slash_formatter::encode_wide("path");
+///
+/// slash_formatter::add_end_backslash_wide_in_place(&mut v);
+///
+/// assert_eq!(slash_formatter::encode_wide("path\\"), v);
+/// ```
+#[inline]
+pub fn add_end_backslash_wide_in_place(s: &mut Vec<u16>) {
+    add_end_in_place(s, b'\\' as u16)
+}
+
+/// Concatenate two strings of UTF-16 code units with a backslash. Unpaired surrogates are kept.
+///
+/// ```
+/// assert_eq!(
+///     vec![0x61, 0x5C, 0xD800],
+///     slash_formatter::concat_with_backslash_wide(vec![0x61], [0xD800, 0x5C])
+/// );
+/// ```
+#[inline]
+pub fn concat_with_backslash_wide<S1: Into<Vec<u16>>, S2: AsRef<[u16]>>(
+    s1: S1,
+    s2: S2,
+) -> Vec<u16> {
+    let mut s1 = s1.into();
+
+    concat_with_backslash_wide_in_place(&mut s1, s2);
+
+    s1
+}
+
+/// Concatenate two strings of UTF-16 code units with a backslash. Unpaired surrogates are kept.
+///
+/// ```
+/// let mut v = slash_formatter::encode_wide("path");
+///
+/// slash_formatter::concat_with_backslash_wide_in_place(
+///     &mut v,
+///     slash_formatter::encode_wide("to\\"),
+/// );
+///
+/// assert_eq!(slash_formatter::encode_wide("path\\to"), v);
+/// ```
+#[inline]
+pub fn concat_with_backslash_wide_in_place<S2: AsRef<[u16]>>(s1: &mut Vec<u16>, s2: S2) {
+    concat_with_in_place(s1, s2.as_ref(), b'\\' as u16)
+}
+
+/// Create an iterator over the segments of UTF-16 code units separated by backslashes.
+///
+/// ```
+/// let v = slash_formatter::encode_wide("\\path\\to\\");
+///
+/// let segments: Vec<String> = slash_formatter::backslash_wide_segments(&v)
+///     .map(|segment| slash_formatter::decode_wide(segment).unwrap())
+///     .collect();
+///
+/// assert_eq!(vec!["path", "to"], segments);
+/// ```
+#[inline]
+pub fn backslash_wide_segments<S: ?Sized + AsRef<[u16]>>(s: &S) -> WideSegments<'_> {
+    WideSegments::new(s.as_ref(), b'\\' as u16)
+}
+
+/// Delete an ending `FILE_SEPARATOR` in UTF-16 code units except for just `FILE_SEPARATOR`.
+///
+/// ```
+/// let v = slash_formatter::encode_wide(concat!(
+///     "path",
+///     slash_formatter::file_separator!()
+/// ));
+///
+/// assert_eq!(
+///     slash_formatter::encode_wide("path"),
+///     slash_formatter::delete_end_file_separator_wide(&v)
+/// );
+/// ```
+#[inline]
+pub fn delete_end_file_separator_wide<S: ?Sized + AsRef<[u16]>>(s: &S) -> &[u16] {
+    #[cfg(unix)]
+    {
+        delete_end_slash_wide(s)
+    }
+
+    #[cfg(windows)]
+    {
+        delete_end_backslash_wide(s)
+    }
+}
+
+/// Delete an ending `FILE_SEPARATOR` in UTF-16 code units except for just `FILE_SEPARATOR`.
+#[inline]
+pub fn delete_end_file_separator_wide_in_place(s: &mut Vec<u16>) {
+    #[cfg(unix)]
+    {
+        delete_end_slash_wide_in_place(s)
+    }
+
+    #[cfg(windows)]
+    {
+        delete_end_backslash_wide_in_place(s)
+    }
+}
+
+/// Delete a starting `FILE_SEPARATOR` in UTF-16 code units except for just `FILE_SEPARATOR`.
+///
+/// ```
+/// let v = slash_formatter::encode_wide(concat!(
+///     slash_formatter::file_separator!(),
+///     "path"
+/// ));
+///
+/// assert_eq!(
+///     slash_formatter::encode_wide("path"),
+///     slash_formatter::delete_start_file_separator_wide(&v)
+/// );
+/// ```
+#[inline]
+pub fn delete_start_file_separator_wide<S: ?Sized + AsRef<[u16]>>(s: &S) -> &[u16] {
+    #[cfg(unix)]
+    {
+        delete_start_slash_wide(s)
+    }
+
+    #[cfg(windows)]
+    {
+        delete_start_backslash_wide(s)
+    }
+}
+
+/// Delete a starting `FILE_SEPARATOR` in UTF-16 code units except for just `FILE_SEPARATOR`.
+#[inline]
+pub fn delete_start_file_separator_wide_in_place(s: &mut Vec<u16>) {
+    #[cfg(unix)]
+    {
+        delete_start_slash_wide_in_place(s)
+    }
+
+    #[cfg(windows)]
+    {
+        delete_start_backslash_wide_in_place(s)
+    }
+}
+
+/// Add a starting `FILE_SEPARATOR` into UTF-16 code units.
+#[inline]
+pub fn add_start_file_separator_wide<S: ?Sized + AsRef<[u16]>>(s: &S) -> Cow<'_, [u16]> {
+    #[cfg(unix)]
+    {
+        add_start_slash_wide(s)
+    }
+
+    #[cfg(windows)]
+    {
+        add_start_backslash_wide(s)
+    }
+}
+
+/// Add a starting `FILE_SEPARATOR` into UTF-16 code units.
+#[inline]
+pub fn add_start_file_separator_wide_in_place(s: &mut Vec<u16>) {
+    #[cfg(unix)]
+    {
+        add_start_slash_wide_in_place(s)
+    }
+
+    #[cfg(windows)]
+    {
+        add_start_backslash_wide_in_place(s)
+    }
+}
+
+/// Add an ending `FILE_SEPARATOR` into UTF-16 code units.
+///
+/// ```
+/// let v = slash_formatter::encode_wide("path");
+///
+/// assert_eq!(
+///     slash_formatter::encode_wide(concat!(
+///         "path",
+///         slash_formatter::file_separator!()
+///     )),
+///     slash_formatter::add_end_file_separator_wide(&v).as_ref()
+/// );
+/// ```
+#[inline]
+pub fn add_end_file_separator_wide<S: ?Sized + AsRef<[u16]>>(s: &S) -> Cow<'_, [u16]> {
+    #[cfg(unix)]
+    {
+        add_end_slash_wide(s)
+    }
+
+    #[cfg(windows)]
+    {
+        add_end_backslash_wide(s)
+    }
+}
+
+/// Add an ending `FILE_SEPARATOR` into UTF-16 code units.
+#[inline]
+pub fn add_end_file_separator_wide_in_place(s: &mut Vec<u16>) {
+    #[cfg(unix)]
+    {
+        add_end_slash_wide_in_place(s)
+    }
+
+    #[cfg(windows)]
+    {
+        add_end_backslash_wide_in_place(s)
+    }
+}
+
+/// Concatenate two strings of UTF-16 code units with `FILE_SEPARATOR`.
+///
+/// ```
+/// let v = slash_formatter::concat_with_file_separator_wide(
+///     slash_formatter::encode_wide("path"),
+///     slash_formatter::encode_wide("to"),
+/// );
+///
+/// assert_eq!(
+///     slash_formatter::encode_wide(
+///         slash_formatter::concat_with_file_separator!("path", "to")
+///     ),
+///     v
+/// );
+/// ```
+#[inline]
+pub fn concat_with_file_separator_wide<S1: Into<Vec<u16>>, S2: AsRef<[u16]>>(
+    s1: S1,
+    s2: S2,
+) -> Vec<u16> {
+    #[cfg(unix)]
+    {
+        concat_with_slash_wide(s1, s2)
+    }
+
+    #[cfg(windows)]
+    {
+        concat_with_backslash_wide(s1, s2)
+    }
+}
+
+/// Concatenate two strings of UTF-16 code units with `FILE_SEPARATOR`.
+#[inline]
+pub fn concat_with_file_separator_wide_in_place<S2: AsRef<[u16]>>(s1: &mut Vec<u16>, s2: S2) {
+    #[cfg(unix)]
+    {
+        concat_with_slash_wide_in_place(s1, s2)
+    }
+
+    #[cfg(windows)]
+    {
+        concat_with_backslash_wide_in_place(s1, s2)
+    }
+}
+
+/// Create an iterator over the segments of UTF-16 code units separated by `FILE_SEPARATOR`.
+///
+/// ```
+/// let v = slash_formatter::encode_wide(
+///     slash_formatter::concat_with_file_separator!("path", "to"),
+/// );
+///
+/// assert_eq!(2, slash_formatter::file_separator_wide_segments(&v).count());
+/// ```
+#[inline]
+pub fn file_separator_wide_segments<S: ?Sized + AsRef<[u16]>>(s: &S) -> WideSegments<'_> {
+    #[cfg(unix)]
+    {
+        slash_wide_segments(s)
+    }
+
+    #[cfg(windows)]
+    {
+        backslash_wide_segments(s)
+    }
+}
+
+/**
+Concatenate multiple strings of UTF-16 code units with slashes. It can also be used to get the literal slash as a `u16`.
+
+```
+let v = slash_formatter::slash_wide!(slash_formatter::encode_wide("path"), slash_formatter::encode_wide("to/"));
+
+assert_eq!(slash_formatter::encode_wide("path/to"), v);
+```
+*/
+#[macro_export]
+macro_rules! slash_wide {
+    () => {
+        b'/' as u16
+    };
+    ($s:expr $(, $sc:expr)* $(,)*) => {
+        {
+            let mut s = $s.to_vec();
+
+            $(
+                $crate::concat_with_slash_wide_in_place(&mut s, $sc);
+            )*
+
+            s
+        }
+    };
+}
+
+/**
+Concatenate multiple strings of UTF-16 code units with slashes. It can also be used to get the literal slash as a `u16`.
+
+```
+let mut v = slash_formatter::encode_wide("path");
+
+slash_formatter::slash_wide_in_place!(&mut v, slash_formatter::encode_wide("to/"));
+
+assert_eq!(slash_formatter::encode_wide("path/to"), v);
+```
+*/
+#[macro_export]
+macro_rules! slash_wide_in_place {
+    () => {
+        b'/' as u16
+    };
+    ($s:expr $(, $sc:expr)* $(,)*) => {
+        $(
+            $crate::concat_with_slash_wide_in_place($s, $sc);
+        )*
+    };
+}
+
+/**
+Concatenate multiple strings of UTF-16 code units with backslashes. It can also be used to get the literal backslash as a `u16`.
+
+```
+let v = slash_formatter::backslash_wide!(slash_formatter::encode_wide("path"), slash_formatter::encode_wide("to\\"));
+
+assert_eq!(slash_formatter::encode_wide("path\\to"), v);
+```
+*/
+#[macro_export]
+macro_rules! backslash_wide {
+    () => {
+        b'\\' as u16
+    };
+    ($s:expr $(, $sc:expr)* $(,)*) => {
+        {
+            let mut s = $s.to_vec();
+
+            $(
+                $crate::concat_with_backslash_wide_in_place(&mut s, $sc);
+            )*
+
+            s
+        }
+    };
+}
+
+/**
+Concatenate multiple strings of UTF-16 code units with backslashes. It can also be used to get the literal backslash as a `u16`.
+
+```
+let mut v = slash_formatter::encode_wide("path");
+
+slash_formatter::backslash_wide_in_place!(&mut v, slash_formatter::encode_wide("to\\"));
+
+assert_eq!(slash_formatter::encode_wide("path\\to"), v);
+```
+*/
+#[macro_export]
+macro_rules! backslash_wide_in_place {
+    () => {
+        b'\\' as u16
+    };
+    ($s:expr $(, $sc:expr)* $(,)*) => {
+        $(
+            $crate::concat_with_backslash_wide_in_place($s, $sc);
+        )*
+    };
+}
+
+#[cfg(unix)]
+/**
+Concatenate multiple strings of UTF-16 code units with `FILE_SEPARATOR`. It can also be used to get the literal `FILE_SEPARATOR` as a `u16`.
+
+```
+let v = slash_formatter::file_separator_wide!(slash_formatter::encode_wide("path"), slash_formatter::encode_wide("to"));
+
+assert_eq!(slash_formatter::encode_wide(slash_formatter::concat_with_file_separator!("path", "to")), v);
+```
+*/
+#[macro_export]
+macro_rules! file_separator_wide {
+    ($($t:tt)*) => {
+        $crate::slash_wide!($($t)*)
+    };
+}
+
+#[cfg(windows)]
+/**
+Concatenate multiple strings of UTF-16 code units with `FILE_SEPARATOR`. It can also be used to get the literal `FILE_SEPARATOR` as a `u16`.
+
+```
+let v = slash_formatter::file_separator_wide!(slash_formatter::encode_wide("path"), slash_formatter::encode_wide("to"));
+
+assert_eq!(slash_formatter::encode_wide(slash_formatter::concat_with_file_separator!("path", "to")), v);
+```
+*/
+#[macro_export]
+macro_rules! file_separator_wide {
+    ($($t:tt)*) => {
+        $crate::backslash_wide!($($t)*)
+    };
+}
+
+#[cfg(unix)]
+/**
+Concatenate multiple strings of UTF-16 code units with `FILE_SEPARATOR`. It can also be used to get the literal `FILE_SEPARATOR` as a `u16`.
+
+```
+let mut v = slash_formatter::encode_wide("path");
+
+slash_formatter::file_separator_wide_in_place!(&mut v, slash_formatter::encode_wide("to"));
+
+assert_eq!(slash_formatter::encode_wide(slash_formatter::concat_with_file_separator!("path", "to")), v);
+```
+*/
+#[macro_export]
+macro_rules! file_separator_wide_in_place {
+    ($($t:tt)*) => {
+        $crate::slash_wide_in_place!($($t)*)
+    };
+}
+
+#[cfg(windows)]
+/**
+Concatenate multiple strings of UTF-16 code units with `FILE_SEPARATOR`. It can also be used to get the literal `FILE_SEPARATOR` as a `u16`.
+
+```
+let mut v = slash_formatter::encode_wide("path");
+
+slash_formatter::file_separator_wide_in_place!(&mut v, slash_formatter::encode_wide("to"));
+
+assert_eq!(slash_formatter::encode_wide(slash_formatter::concat_with_file_separator!("path", "to")), v);
+```
+*/
+#[macro_export]
+macro_rules! file_separator_wide_in_place {
+    ($($t:tt)*) => {
+        $crate::backslash_wide_in_place!($($t)*)
+    };
+}
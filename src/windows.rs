@@ -0,0 +1,43 @@
+/// Check whether a string starts with a drive letter such as `C:`.
+#[inline]
+pub(crate) fn has_drive_letter(s: &str) -> bool {
+    let bytes = s.as_bytes();
+
+    bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
+
+/// Check whether a string starts with a UNC prefix, `\\`.
+#[inline]
+pub(crate) fn has_unc_prefix(s: &str) -> bool {
+    s.starts_with("\\\\")
+}
+
+/// Check whether a character cannot be used in a file name on Windows.
+#[inline]
+pub(crate) fn is_illegal_char(c: char) -> bool {
+    matches!(c, '<' | '>' | ':' | '"' | '|' | '?' | '*' | '\0'..='\x1F')
+}
+
+/// Check whether a segment is a reserved device name on Windows, such as `CON` or `aux.txt`.
+pub(crate) fn is_reserved_name(segment: &str) -> bool {
+    let stem = match segment.find('.') {
+        Some(index) => &segment[..index],
+        None => segment,
+    };
+
+    let stem = stem.trim_end_matches(' ').as_bytes();
+
+    match stem.len() {
+        3 => ["CON", "PRN", "AUX", "NUL"]
+            .iter()
+            .any(|name| stem.eq_ignore_ascii_case(name.as_bytes())),
+        4 => {
+            (stem[..3].eq_ignore_ascii_case(b"COM") || stem[..3].eq_ignore_ascii_case(b"LPT"))
+                && (b'1'..=b'9').contains(&stem[3])
+        },
+        _ => false,
+    }
+}
+
+/// The maximum length of a segment, in UTF-16 code units on Windows or in bytes otherwise.
+pub(crate) const MAX_SEGMENT_LENGTH: usize = 255;